@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::aoc::common::PuzzleResult;
+use crate::cache::write_atomic;
+use crate::error::AocError;
+
+#[derive(Default, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+/// Accepted answers of one year, stored as `{dir}/{year}.toml` with a `[dayNN]` table per day.
+pub struct AnswerStore {
+    path: PathBuf,
+    days: BTreeMap<String, DayAnswers>,
+}
+
+impl AnswerStore {
+    pub fn load(dir: &Path, year: u16) -> Result<Self, AocError> {
+        let path = dir.join(format!("{year}.toml"));
+        let days = match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).map_err(|err| AocError::Io(format!("invalid answers file {}: {err}", path.display())))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(AocError::Io(format!("failed to read answers file {}: {err}", path.display()))),
+        };
+        Ok(AnswerStore { path, days })
+    }
+
+    /// The accepted answer of a part, parsed so that it compares equal to the computed one even if the file was
+    /// edited by hand. An empty answer counts as none.
+    pub fn get(&self, day: u8, part: u8) -> Option<PuzzleResult> {
+        let answers = self.days.get(&key(day))?;
+        let answer = match part {
+            1 => answers.part1.as_deref(),
+            _ => answers.part2.as_deref(),
+        };
+        answer?.parse().ok()
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &PuzzleResult) {
+        let answers = self.days.entry(key(day)).or_default();
+        match part {
+            1 => answers.part1 = Some(answer.to_string()),
+            _ => answers.part2 = Some(answer.to_string()),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), AocError> {
+        let text = toml::to_string(&self.days).map_err(|err| AocError::Io(format!("failed to serialize answers: {err}")))?;
+        write_atomic(&self.path, &text).map_err(|err| AocError::Io(format!("failed to write answers file {}: {err}", self.path.display())))
+    }
+}
+
+fn key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::AnswerStore;
+    use crate::aoc::common::PuzzleResult;
+
+    #[test]
+    fn record_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = AnswerStore::load(dir.path(), 2023).unwrap();
+        assert_eq!(store.get(5, 1), None);
+        store.record(5, 1, &PuzzleResult::Number(35));
+        store.record(12, 2, &PuzzleResult::Number(525152));
+        store.save().unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("2023.toml")).unwrap(),
+            "[day05]\npart1 = \"35\"\n\n[day12]\npart2 = \"525152\"\n"
+        );
+        let store = AnswerStore::load(dir.path(), 2023).unwrap();
+        assert_eq!(store.get(5, 1), Some(PuzzleResult::Number(35)));
+        assert_eq!(store.get(5, 2), None);
+        assert_eq!(store.get(12, 2), Some(PuzzleResult::Number(525152)));
+    }
+
+    #[test]
+    fn edited_by_hand() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("2023.toml"), "[day08]\npart1 = \" 6 \"\npart2 = \"\"\n").unwrap();
+        let store = AnswerStore::load(dir.path(), 2023).unwrap();
+        assert_eq!(store.get(8, 1), Some(PuzzleResult::Number(6)));
+        assert_eq!(store.get(8, 2), None);
+    }
+
+    #[test]
+    fn invalid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("2023.toml"), "[day05\n").unwrap();
+        assert!(AnswerStore::load(dir.path(), 2023).is_err());
+    }
+}
@@ -1,21 +1,344 @@
 use std::fmt;
 use std::fmt::Formatter;
+use std::panic::AssertUnwindSafe;
+use std::str::FromStr;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+use std::{panic, thread};
+use clap::ValueEnum;
+use serde::Serialize;
+use crate::aoc::generate::Rng;
+use crate::error::AocError;
 
-#[derive(Ord, PartialOrd, Eq, PartialEq)]
+/// Serialized as a bare number or string, the way the answer is entered on the website. `Big` only holds numbers
+/// that do not fit in a `Number`, the `From` conversions take care of that so equal answers always compare equal.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum PuzzleResult {
     Number(i64),
+    Big(u128),
     Text(String),
 }
 
-impl fmt::Debug for PuzzleResult {
+impl fmt::Display for PuzzleResult {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            PuzzleResult::Number(value) => {
-                write!(f, "PuzzleResult::Number({})", value)
-            }
-            PuzzleResult::Text(value) => {
-                write!(f, "PuzzleResult::Text({})", value)
+            PuzzleResult::Number(value) => write!(f, "{}", value),
+            PuzzleResult::Big(value) => write!(f, "{}", value),
+            PuzzleResult::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Parses an answer as it is entered on the website, anything that is not an integer is text.
+impl FromStr for PuzzleResult {
+    type Err = AocError;
+
+    fn from_str(answer: &str) -> Result<Self, Self::Err> {
+        let answer = answer.trim();
+        if answer.is_empty() {
+            return Err(AocError::Usage(String::from("an answer cannot be empty")));
+        }
+        Ok(match (answer.parse::<i64>(), answer.parse::<u128>()) {
+            (Ok(value), _) => PuzzleResult::Number(value),
+            (_, Ok(value)) => PuzzleResult::Big(value),
+            _ => PuzzleResult::Text(answer.to_string()),
+        })
+    }
+}
+
+impl From<i64> for PuzzleResult {
+    fn from(value: i64) -> Self {
+        PuzzleResult::Number(value)
+    }
+}
+
+impl From<u128> for PuzzleResult {
+    fn from(value: u128) -> Self {
+        i64::try_from(value).map_or(PuzzleResult::Big(value), PuzzleResult::Number)
+    }
+}
+
+impl From<u64> for PuzzleResult {
+    fn from(value: u64) -> Self {
+        PuzzleResult::from(u128::from(value))
+    }
+}
+
+impl From<usize> for PuzzleResult {
+    fn from(value: usize) -> Self {
+        PuzzleResult::from(value as u128)
+    }
+}
+
+impl From<String> for PuzzleResult {
+    fn from(value: String) -> Self {
+        PuzzleResult::Text(value)
+    }
+}
+
+impl From<&str> for PuzzleResult {
+    fn from(value: &str) -> Self {
+        PuzzleResult::Text(value.to_string())
+    }
+}
+
+/// A day of the Advent of Code whose input is parsed once and shared by both parts.
+pub trait Puzzle: Sync {
+    type Input: Send + Sync + 'static;
+
+    const DAY: u8;
+
+    const TITLE: &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> Result<PuzzleResult, AocError>;
+
+    fn part2(input: &Self::Input) -> Result<PuzzleResult, AocError>;
+
+    /// A random well-formed input whose amount of data grows with `size`, which is at least 1. `None` for a day
+    /// without a generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Stack size of every thread that runs a solver. The 2 MiB default of spawned threads is too small for solvers that
+/// recurse as deep as their input is large.
+pub const SOLVER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Which parts of a day to solve.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn includes(self, part: u8) -> bool {
+        matches!((self, part), (Part::Both, _) | (Part::One, 1) | (Part::Two, 2))
+    }
+}
+
+/// Answer of a single part and how long it took, or why there is none.
+pub type PartRun = Result<(PuzzleResult, Duration), AocError>;
+
+/// Answers of a single day, with parsing and each part timed separately. Parts that were not selected are `None`.
+pub struct Run {
+    pub parse: Duration,
+    pub part1: Option<PartRun>,
+    pub part2: Option<PartRun>,
+}
+
+/// A single day of the Advent of Code, registered in the `SOLVERS` of its year.
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn part1(&self, input: &str) -> Result<PuzzleResult, AocError>;
+
+    fn part2(&self, input: &str) -> Result<PuzzleResult, AocError>;
+
+    /// Parses the input without solving it, the error points at where it is malformed.
+    fn validate(&self, input: &str) -> Result<(), AocError>;
+
+    /// A random well-formed input, the same `seed` and `size` always give the same input. `None` for a day without a
+    /// generator.
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+
+    /// Parses the input and solves the selected parts, each part failing on its own. A panic becomes an error and a part
+    /// running longer than `timeout` is abandoned.
+    fn run(&self, input: &str, part: Part, timeout: Option<Duration>) -> Result<Run, AocError>;
+}
+
+impl<P: Puzzle> Solver for P {
+    fn day(&self) -> u8 { P::DAY }
+
+    fn title(&self) -> &'static str { P::TITLE }
+
+    fn part1(&self, input: &str) -> Result<PuzzleResult, AocError> {
+        catch_panic(P::DAY, || <P as Puzzle>::part1(&parse::<P>(input)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<PuzzleResult, AocError> {
+        catch_panic(P::DAY, || <P as Puzzle>::part2(&parse::<P>(input)?))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), AocError> {
+        catch_panic(P::DAY, || parse::<P>(input).map(|_| ()))
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        P::generate(&mut Rng::new(seed), size.max(1))
+    }
+
+    fn run(&self, input: &str, part: Part, timeout: Option<Duration>) -> Result<Run, AocError> {
+        let (input, parse) = timed(|| catch_panic(P::DAY, || parse::<P>(input)));
+        let input = Arc::new(input?);
+        let solve = |number: u8, f: fn(&P::Input) -> Result<PuzzleResult, AocError>| {
+            part.includes(number).then(|| solve_part::<P>(&input, number, f, timeout))
+        };
+        Ok(Run {
+            parse,
+            part1: solve(1, <P as Puzzle>::part1),
+            part2: solve(2, <P as Puzzle>::part2),
+        })
+    }
+}
+
+/// No puzzle has an empty input, so a download or copy that went wrong is caught here for every day.
+fn parse<P: Puzzle>(input: &str) -> Result<P::Input, AocError> {
+    if input.trim().is_empty() {
+        return Err(AocError::parse(P::DAY, 1, "the input is empty"));
+    }
+    P::parse(input)
+}
+
+/// Without a timeout the part runs on the calling thread. Otherwise it gets a thread of its own, which is left behind
+/// when it does not finish in time since a thread cannot be stopped from the outside.
+fn solve_part<P: Puzzle>(
+    input: &Arc<P::Input>,
+    part: u8,
+    f: fn(&P::Input) -> Result<PuzzleResult, AocError>,
+    timeout: Option<Duration>,
+) -> PartRun {
+    let solve = move |input: &P::Input| catch_panic(P::DAY, || {
+        let (result, runtime) = timed(|| f(input));
+        Ok((result?, runtime))
+    });
+    let Some(timeout) = timeout else { return solve(input); };
+    let (sender, receiver) = mpsc::channel();
+    let input = Arc::clone(input);
+    thread::Builder::new()
+        .name(format!("day{:02}-part{part}", P::DAY))
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || { let _ = sender.send(solve(&input)); })
+        .map_err(|err| AocError::Io(format!("failed to start a solver thread: {err}")))?;
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(AocError::TimedOut { day: P::DAY, part, timeout }),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(AocError::Panicked { day: P::DAY, msg: String::from("the solver thread exited") }),
+    }
+}
+
+fn catch_panic<T>(day: u8, f: impl FnOnce() -> Result<T, AocError>) -> Result<T, AocError> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let msg = payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("unknown panic payload"));
+        Err(AocError::Panicked { day, msg })
+    })
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let now = Instant::now();
+    let value = f();
+    (value, now.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::aoc::common::{Part, Puzzle, PuzzleResult, Solver};
+    use crate::error::AocError;
+
+    struct Faulty;
+
+    impl Puzzle for Faulty {
+        type Input = u64;
+
+        const DAY: u8 = 26;
+
+        const TITLE: &'static str = "Faulty";
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            Ok(input.parse::<u64>().expect("not a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Result<PuzzleResult, AocError> {
+            std::thread::sleep(Duration::from_millis(*input));
+            Ok(PuzzleResult::Number(*input as i64))
+        }
+
+        fn part2(_: &Self::Input) -> Result<PuzzleResult, AocError> {
+            panic!("part 2 is broken");
+        }
+    }
+
+    /// Recurses with a kilobyte of stack per level, deeper than the 2 MiB default stack of a thread allows.
+    struct Deep;
+
+    impl Puzzle for Deep {
+        type Input = usize;
+
+        const DAY: u8 = 27;
+
+        const TITLE: &'static str = "Deep";
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            Ok(input.parse::<usize>().expect("not a number"))
+        }
+
+        fn part1(depth: &Self::Input) -> Result<PuzzleResult, AocError> {
+            fn descend(depth: usize) -> usize {
+                let frame = std::hint::black_box([1u8; 1024]);
+                if depth == 0 { 0 } else { descend(depth - 1) + usize::from(frame[0]) }
             }
+            Ok(PuzzleResult::from(descend(*depth)))
+        }
+
+        fn part2(depth: &Self::Input) -> Result<PuzzleResult, AocError> {
+            <Deep as Puzzle>::part1(depth)
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn answers() {
+        assert_eq!("  1234\n".parse(), Ok(PuzzleResult::Number(1234)));
+        assert_eq!("-5".parse(), Ok(PuzzleResult::Number(-5)));
+        assert_eq!("18446744073709551616".parse(), Ok(PuzzleResult::Big(1 << 64)));
+        assert_eq!("LRRL".parse(), Ok(PuzzleResult::Text(String::from("LRRL"))));
+        assert!(" ".parse::<PuzzleResult>().is_err());
+        assert_eq!(PuzzleResult::from(7u128), PuzzleResult::Number(7));
+        assert_eq!(PuzzleResult::from(u64::MAX), PuzzleResult::Big(u64::MAX.into()));
+        assert_eq!(PuzzleResult::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(PuzzleResult::from("ab,cd").to_string(), "ab,cd");
+        assert!(PuzzleResult::Number(i64::MAX) < PuzzleResult::from(u64::MAX));
+        assert_eq!(serde_json::to_string(&PuzzleResult::from(u64::MAX)).unwrap(), "18446744073709551615");
+    }
+
+    #[test]
+    fn panics_are_caught() {
+        let run = Faulty.run("0", Part::Both, None).unwrap();
+        assert_eq!(run.part1.unwrap().unwrap().0, PuzzleResult::Number(0));
+        assert_eq!(run.part2.unwrap().unwrap_err(), AocError::Panicked { day: 26, msg: String::from("part 2 is broken") });
+        assert_eq!(
+            Faulty.run("x", Part::Both, None).err(),
+            Some(AocError::Panicked { day: 26, msg: String::from("not a number: ParseIntError { kind: InvalidDigit }") })
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        let timeout = Some(Duration::from_millis(50));
+        let run = Faulty.run("5000", Part::One, timeout).unwrap();
+        assert_eq!(run.part1.unwrap().unwrap_err(), AocError::TimedOut { day: 26, part: 1, timeout: Duration::from_millis(50) });
+        assert!(run.part2.is_none());
+        let run = Faulty.run("1", Part::Both, timeout).unwrap();
+        assert_eq!(run.part1.unwrap().unwrap().0, PuzzleResult::Number(1));
+        assert!(matches!(run.part2.unwrap(), Err(AocError::Panicked { .. })));
+    }
+
+    #[test]
+    fn deep_recursion_with_timeout() {
+        let run = Deep.run("16384", Part::One, Some(Duration::from_secs(60))).unwrap();
+        assert_eq!(run.part1.unwrap().unwrap().0, PuzzleResult::Number(16384));
+    }
+}
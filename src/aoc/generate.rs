@@ -0,0 +1,75 @@
+use std::ops::RangeInclusive;
+
+/// A small seeded random number generator (SplitMix64) for the input generators. It is part of the crate rather than a
+/// dependency so that a seed gives the same input in every version.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below `bound`, which must not be 0.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "the bound of a random number must not be 0");
+        ((u128::from(self.next_u64()) * u128::from(bound)) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        start + self.below((end - start) as u64 + 1) as usize
+    }
+
+    /// True with a probability of `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+/// The rows of a map as input text, each row on a line of its own.
+pub fn text(rows: &[Vec<char>]) -> String {
+    rows.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::generate::{text, Rng};
+
+    #[test]
+    fn seeded() {
+        let numbers = |seed| { let mut rng = Rng::new(seed); (0..4).map(|_| rng.next_u64()).collect::<Vec<u64>>() };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert_eq!(numbers(0)[0], 0xe220_a839_7b1d_cdaf);
+
+        let mut rng = Rng::new(1);
+        assert!((0..1000).map(|_| rng.range(3..=5)).all(|x| (3..=5).contains(&x)));
+        assert!((0..1000).all(|_| !rng.chance(0.0) && rng.chance(1.0)));
+        let mut items = (0..10).collect::<Vec<u8>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..10).collect::<Vec<u8>>());
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<u8>>());
+        assert_eq!(text(&[vec!['#', '.'], vec!['.', '#']]), "#.\n.#\n");
+    }
+}
@@ -0,0 +1,238 @@
+use std::str::FromStr;
+use crate::error::AocError;
+
+/// Lines of a puzzle input numbered from 1, a line break at the end of the input does not start another line.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// Reads a line of a puzzle input token by token, every error tells the line and column at which the input differs
+/// from what was expected.
+pub struct Scanner<'a> {
+    day: u8,
+    line: usize,
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(day: u8, line: usize, text: &'a str) -> Self {
+        Scanner { day, line, text, pos: 0 }
+    }
+
+    /// The 1-based column of the next character.
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    /// An error at the next character.
+    pub fn error(&self, msg: impl Into<String>) -> AocError {
+        self.error_at(self.column(), msg)
+    }
+
+    /// An error at an earlier column of the line, for a value that turns out to be wrong only after it was read.
+    pub fn error_at(&self, column: usize, msg: impl Into<String>) -> AocError {
+        AocError::parse_at(self.day, self.line, column, msg)
+    }
+
+    /// An error saying what was expected and what was found instead, the next character.
+    pub fn expected(&self, what: &str) -> AocError {
+        self.expected_found(what, 1)
+    }
+
+    fn expected_found(&self, what: &str, chars: usize) -> AocError {
+        let found = match self.rest().chars().take(chars).collect::<String>() {
+            found if found.is_empty() => String::from("the end of the line"),
+            found => format!("`{found}`"),
+        };
+        self.error(format!("expected {what}, found {found}"))
+    }
+
+    /// Skips `token` if the input continues with it.
+    pub fn eat(&mut self, token: &str) -> bool {
+        let found = self.rest().starts_with(token);
+        if found {
+            self.pos += token.len();
+        }
+        found
+    }
+
+    pub fn expect(&mut self, token: &str) -> Result<(), AocError> {
+        if self.eat(token) { Ok(()) } else { Err(self.expected_found(&format!("`{token}`"), token.chars().count())) }
+    }
+
+    /// Whichever of `tokens` the input continues with, the first one wins if several do.
+    pub fn one_of(&mut self, tokens: &[&'static str]) -> Result<&'static str, AocError> {
+        if let Some(&token) = tokens.iter().find(|token| self.eat(token)) {
+            return Ok(token);
+        }
+        let quoted = tokens.iter().map(|token| format!("`{token}`")).collect::<Vec<String>>();
+        let longest = tokens.iter().map(|token| token.chars().count()).max().unwrap_or(1);
+        let what = match quoted.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+            None => String::from("nothing"),
+        };
+        Err(self.expected_found(&what, longest))
+    }
+
+    /// The next character if it matches `f`, `what` describes it for the error.
+    pub fn char(&mut self, what: &str, f: impl Fn(char) -> bool) -> Result<char, AocError> {
+        match self.rest().chars().next() {
+            Some(c) if f(c) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            }
+            _ => Err(self.expected(what)),
+        }
+    }
+
+    /// Skips any spaces and returns how many there were.
+    pub fn spaces(&mut self) -> usize {
+        self.take_while(|c| c == ' ').len()
+    }
+
+    /// The longest run of characters matching `f`, possibly empty.
+    pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// A non-empty run of characters matching `f`, `what` describes them for the error.
+    pub fn token(&mut self, what: &str, f: impl Fn(char) -> bool) -> Result<&'a str, AocError> {
+        match self.take_while(f) {
+            "" => Err(self.expected(what)),
+            token => Ok(token),
+        }
+    }
+
+    /// A decimal number, negative if it starts with `-`. On an error nothing is read and it points at the start of the
+    /// number.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, AocError> where T::Err: std::fmt::Display {
+        let start = self.pos;
+        self.eat("-");
+        if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+            self.pos = start;
+            return Err(self.expected("a number"));
+        }
+        let number = &self.text[start..self.pos];
+        number.parse::<T>().map_err(|err| {
+            self.pos = start;
+            self.error(format!("{number}: {err}"))
+        })
+    }
+
+    /// Numbers separated by `separator`, at least one.
+    pub fn numbers<T: FromStr>(&mut self, separator: &str) -> Result<Vec<T>, AocError> where T::Err: std::fmt::Display {
+        let mut numbers = vec![self.number()?];
+        while self.eat(separator) {
+            numbers.push(self.number()?);
+        }
+        Ok(numbers)
+    }
+
+    /// Numbers separated by one or more spaces, at least one. Stops at anything else that follows a space.
+    pub fn spaced_numbers<T: FromStr>(&mut self) -> Result<Vec<T>, AocError> where T::Err: std::fmt::Display {
+        let mut numbers = vec![self.number()?];
+        while self.spaces() > 0 && self.rest().starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+            numbers.push(self.number()?);
+        }
+        Ok(numbers)
+    }
+
+    /// Fails unless the whole line was read.
+    pub fn end(&self) -> Result<(), AocError> {
+        if self.is_at_end() { Ok(()) } else { Err(self.expected("the end of the line")) }
+    }
+}
+
+/// Checks that the lines form a rectangle of characters matching `f`, `what` describes them for the errors.
+pub fn grid(day: u8, lines: &[(usize, &str)], what: &str, f: impl Fn(char) -> bool) -> Result<(), AocError> {
+    let width = lines.first().map_or(0, |(_, line)| line.chars().count());
+    for &(line_nr, line) in lines {
+        let mut scanner = Scanner::new(day, line_nr, line);
+        for _ in 0..width.max(1) {
+            scanner.char(what, &f)?;
+        }
+        scanner.end()?;
+    }
+    Ok(())
+}
+
+/// The line of an error with a caret under its column, for showing where the input is malformed.
+pub fn excerpt(input: &str, err: &AocError) -> Option<String> {
+    let AocError::Parse { line, column, .. } = *err else { return None };
+    let text = input.lines().nth(line.checked_sub(1)?)?;
+    let margin = " ".repeat(line.to_string().len());
+    let caret = column.map_or_else(String::new, |column| format!("\n{margin} | {}^", " ".repeat(column - 1)));
+    Some(format!("{line} | {text}{caret}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::input::{excerpt, grid, lines, Scanner};
+    use crate::error::AocError;
+
+    #[test]
+    fn scans_tokens() {
+        let mut line = Scanner::new(2, 4, "Game 12: 3 blue, -4 red");
+        line.expect("Game ").unwrap();
+        assert_eq!(line.number::<u32>(), Ok(12));
+        line.expect(":").unwrap();
+        line.spaces();
+        assert_eq!(line.number::<i64>(), Ok(3));
+        line.spaces();
+        assert_eq!(line.token("a color", |c| c.is_ascii_lowercase()), Ok("blue"));
+        assert!(line.eat(", "));
+        assert_eq!(line.number::<u8>(), Err(AocError::parse_at(2, 4, 18, "-4: invalid digit found in string")));
+        assert_eq!(line.number::<i8>(), Ok(-4));
+        assert_eq!(line.end(), Err(AocError::parse_at(2, 4, 20, "expected the end of the line, found ` `")));
+        line.spaces();
+        assert_eq!(line.expect("blue"), Err(AocError::parse_at(2, 4, 21, "expected `blue`, found `red`")));
+        assert_eq!(line.one_of(&["green", "blue"]), Err(AocError::parse_at(2, 4, 21, "expected `green` or `blue`, found `red`")));
+        assert_eq!(line.char("a digit", |c| c.is_ascii_digit()), Err(AocError::parse_at(2, 4, 21, "expected a digit, found `r`")));
+        assert_eq!(line.char("a letter", |c| c.is_ascii_lowercase()), Ok('r'));
+        line.expect("ed").unwrap();
+        assert_eq!(line.number::<u8>(), Err(AocError::parse_at(2, 4, 24, "expected a number, found the end of the line")));
+        assert!(line.end().is_ok());
+    }
+
+    #[test]
+    fn number_lists() {
+        assert_eq!(Scanner::new(1, 1, "1,2,3").numbers::<u8>(","), Ok(vec![1, 2, 3]));
+        assert!(Scanner::new(1, 1, "1,,3").numbers::<u8>(",").is_err());
+        let mut line = Scanner::new(1, 1, "7  15   30 ");
+        assert_eq!(line.spaced_numbers::<u8>(), Ok(vec![7, 15, 30]));
+        assert!(line.is_at_end());
+        let mut line = Scanner::new(1, 1, "41 48 | 83");
+        assert_eq!(line.spaced_numbers::<u8>(), Ok(vec![41, 48]));
+        assert_eq!(line.rest(), "| 83");
+        assert_eq!(lines("a\n\nb\n").collect::<Vec<_>>(), vec![(1, "a"), (2, ""), (3, "b")]);
+    }
+
+    #[test]
+    fn grids() {
+        let map = [(1, "#.#"), (2, "..#")];
+        assert!(grid(11, &map, "`.` or `#`", |c| ".#".contains(c)).is_ok());
+        let err = grid(11, &[(1, "#.#"), (2, ".x#")], "`.` or `#`", |c| ".#".contains(c)).unwrap_err();
+        assert_eq!(err, AocError::parse_at(11, 2, 2, "expected `.` or `#`, found `x`"));
+        let err = grid(11, &[(1, "#.#"), (2, ".#")], "`.` or `#`", |c| ".#".contains(c)).unwrap_err();
+        assert_eq!(err, AocError::parse_at(11, 2, 3, "expected `.` or `#`, found the end of the line"));
+        let err = grid(11, &[(1, "#.#"), (2, ".##.")], "`.` or `#`", |c| ".#".contains(c)).unwrap_err();
+        assert_eq!(err.to_string(), "day 11: invalid input on line 2, column 4: expected the end of the line, found `.`");
+        assert!(grid(11, &[(1, "")], "`.` or `#`", |c| ".#".contains(c)).is_err());
+        assert_eq!(excerpt("#.#\n.##.\n", &err).unwrap(), "2 | .##.\n  |    ^");
+        assert_eq!(excerpt("#.#\n", &AocError::parse(6, 1, "x")).unwrap(), "1 | #.#");
+        assert_eq!(excerpt("#.#\n", &AocError::Io(String::new())), None);
+    }
+}
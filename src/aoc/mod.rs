@@ -1,17 +1,56 @@
+use crate::aoc::common::Solver;
+
 pub mod common;
+pub mod generate;
+pub mod input;
 pub mod util;
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
\ No newline at end of file
+pub mod y2023;
+
+/// The implemented days of one Advent of Code.
+pub struct Year {
+    pub year: u16,
+    pub solvers: &'static [&'static dyn Solver],
+}
+
+/// Every year with implemented days, in order. A new year only needs to be added here to be picked up by the runner.
+pub static YEARS: &[Year] = &[
+    Year { year: 2023, solvers: y2023::SOLVERS },
+];
+
+/// Implemented days of a year, `None` for a year without any.
+pub fn solvers(year: u16) -> Option<&'static [&'static dyn Solver]> {
+    YEARS.iter().find(|x| x.year == year).map(|x| x.solvers)
+}
+
+pub fn solver(year: u16, day: u8) -> Option<&'static dyn Solver> {
+    solvers(year)?.iter().copied().find(|solver| solver.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::YEARS;
+
+    #[test]
+    fn registry_is_ordered_by_year() {
+        assert!(YEARS.windows(2).all(|w| w[0].year < w[1].year));
+    }
+
+    /// Every registered solver with a generator parses and solves its inputs, from tiny ones to the size of a puzzle
+    /// input.
+    #[test]
+    fn registered_solvers_solve_generated_inputs() {
+        let mut failures = vec![];
+        for (year, solver) in YEARS.iter().flat_map(|year| year.solvers.iter().map(|solver| (year.year, solver))) {
+            for (seed, size) in [(0, 1), (1, 5), (2, 12), (3, 30), (4, 140)] {
+                let Some(input) = solver.generate(seed, size) else { break };
+                assert_eq!(solver.generate(seed, size).as_ref(), Some(&input));
+                for (part, answer) in [(1, solver.part1(&input)), (2, solver.part2(&input))] {
+                    if let Err(err) = answer {
+                        failures.push(format!("{year} day {} part {part} with seed {seed} and size {size}: {err}\n{input}", solver.day()));
+                    }
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -1,31 +1,28 @@
 use std::ops::{Add, Index, IndexMut, Sub};
 use itertools::Itertools;
 use num::abs;
-#[allow(dead_code)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub(crate) struct Point{
-    pub(crate) x: i64,
-    pub(crate) y: i64
+pub struct Point{
+    pub x: i64,
+    pub y: i64
 }
 
-#[allow(dead_code)]
 impl Point {
 
     // https://en.wikipedia.org/wiki/Taxicab_geometry
-    pub(crate)  fn manhattan(&self, other: &Point) -> i64 { abs(self.x - other.x) + abs(self.y - other.y) }
+    pub fn manhattan(&self, other: &Point) -> i64 { abs(self.x - other.x) + abs(self.y - other.y) }
 
-    pub(crate)  fn delta(&self, dx: i64, dy: i64) -> Point { Point { x: self.x + dx, y: self.y + dy } }
+    pub fn delta(&self, dx: i64, dy: i64) -> Point { Point { x: self.x + dx, y: self.y + dy } }
 
-    pub(crate)  fn left(&self) -> Self { self.delta(-1, 0) }
+    pub fn left(&self) -> Self { self.delta(-1, 0) }
 
-    pub(crate)  fn right(&self) -> Self { self.delta(1, 0) }
+    pub fn right(&self) -> Self { self.delta(1, 0) }
 
-    pub(crate)  fn up(&self) -> Self { self.delta(0, -1) }
+    pub fn up(&self) -> Self { self.delta(0, -1) }
 
-    pub(crate)  fn down(&self) -> Self { self.delta(0, 1) }
+    pub fn down(&self) -> Self { self.delta(0, 1) }
 }
 
-#[allow(dead_code)]
 impl Add for Point {
     type Output = Point;
 
@@ -37,7 +34,6 @@ impl Add for Point {
     }
 }
 
-#[allow(dead_code)]
 impl Sub for Point {
     type Output = Point;
 
@@ -48,57 +44,56 @@ impl Sub for Point {
         }
     }
 }
-#[allow(dead_code)]
 #[derive(Clone, Debug)]
-pub(crate) struct Path {
+pub struct Path {
     points: Vec<Point>
 }
 
-#[allow(dead_code)]
-pub fn count_diff<T: PartialEq>(a: &Vec<T>, b: &Vec<T>) -> usize {
+pub fn count_diff<T: PartialEq>(a: &[T], b: &[T]) -> usize {
      a.iter().zip(b.iter()).filter(|&(a, b)| a != b).count()
 }
 
-#[allow(dead_code)]
 impl Path {
 
-    pub(crate)  fn new(start: Point) -> Self {
+    pub fn new(start: Point) -> Self {
         Path { points: vec![start] }
     }
 
-    pub(crate)  fn push(&mut self, point: Point) {
+    pub fn push(&mut self, point: Point) {
         if self.is_cycle() { return; }
-        if self.first().unwrap().x == point.x && self.first().unwrap().y == point.y && self.len() >= 3 {
-            self.points.push(point)
-        } else if !self.contains(&point) {
+        let closes_cycle = self.first().unwrap().x == point.x && self.first().unwrap().y == point.y && self.len() >= 3;
+        if closes_cycle || !self.contains(&point) {
             self.points.push(point)
         }
     }
 
-    pub(crate)  fn is_cycle(&self) -> bool {
+    pub fn is_cycle(&self) -> bool {
         self.first().is_some_and(|s| self.last().is_some_and(|e| s == e)) && self.len() > 1
     }
 
-    pub(crate)  fn contains(&self, point: &Point) -> bool {
+    pub fn contains(&self, point: &Point) -> bool {
         self.points.contains(point)
     }
 
-    pub(crate)  fn last(&self) -> Option<&Point> {
+    pub fn last(&self) -> Option<&Point> {
         self.points.last()
     }
 
-    pub(crate)  fn first(&self) -> Option<&Point> {
+    pub fn first(&self) -> Option<&Point> {
         self.points.first()
     }
 
-    pub(crate)  fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         self.points.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
 }
 
-#[allow(dead_code)]
-#[derive(Eq, Hash, PartialEq)]
-pub(crate) struct CharMap {
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CharMap {
     map: Vec<Vec<char>>
 }
 
@@ -115,10 +110,10 @@ impl IndexMut<usize> for CharMap {
     }
 }
 
-#[allow(dead_code)]
 impl CharMap {
 
-    pub(crate) fn from_str(input: &str) -> Option<Self> {
+    /// One row per line, `None` for an empty map or one with empty rows.
+    pub fn parse(input: &str) -> Option<Self> {
         let input = input.trim();
         if input.is_empty() { return None }
         let map = CharMap {
@@ -127,20 +122,20 @@ impl CharMap {
                 .map(|x| x.chars().collect_vec())
                 .collect()
         };
-        if map.width() == 0 || map.map.iter().any(|x| x.len() == 0) {
+        if map.width() == 0 || map.map.iter().any(|x| x.is_empty()) {
             None
         } else {
             Some(map)
         }
     }
 
-    pub(crate) fn at(&self, point: &Point) -> Option<char> {
-        if point.y >= self.width() as i64 { return None; }
-        if point.x >= self.height() as i64 { return None; }
-        Some(self.map[point.y as usize][point.x as usize])
+    /// The character at a point, `None` outside of the map.
+    pub fn at(&self, point: &Point) -> Option<char> {
+        let row = self.map.get(usize::try_from(point.y).ok()?)?;
+        row.get(usize::try_from(point.x).ok()?).copied()
     }
 
-    pub(crate) fn col_at(&self, col: usize) -> Vec<char> {
+    pub fn col_at(&self, col: usize) -> Vec<char> {
         let mut cols: Vec<char> = vec![];
         for row in 0..self.map.len() {
             cols.push(self.map[row][col]);
@@ -148,19 +143,15 @@ impl CharMap {
         cols
     }
 
-    pub(crate) fn clone(&self) -> Self {
-        CharMap { map: self.map.iter().map(|x| x.clone()).collect_vec() }
-    }
-
-    pub(crate) fn row_at(&self, row: usize) -> &Vec<char> {
+    pub fn row_at(&self, row: usize) -> &Vec<char> {
         &self.map[row]
     }
 
-    pub(crate) fn width(&self) -> usize { self.map.first().unwrap_or(&vec![]).len() }
+    pub fn width(&self) -> usize { self.map.first().unwrap_or(&vec![]).len() }
 
-    pub(crate) fn height(&self) -> usize { self.map.len() }
+    pub fn height(&self) -> usize { self.map.len() }
 
-    pub(crate) fn print(&self) {
+    pub fn print(&self) {
         for row in 0..self.height() {
             for col in 0..self.width() {
                 print!("{}", self[row][col])
@@ -169,7 +160,7 @@ impl CharMap {
         }
     }
 
-    pub(crate) fn filter_rows(&self, pred: fn(&char) -> bool) -> Vec<i64> {
+    pub fn filter_rows(&self, pred: fn(&char) -> bool) -> Vec<i64> {
         self.map
             .iter()
             .enumerate()
@@ -179,22 +170,14 @@ impl CharMap {
     }
 
 
-    pub(crate) fn filter_cols(&self, pred: fn(&char) -> bool) -> Vec<i64> {
-        let mut cols: Vec<i64> = vec![];
-        for y in 0..self.map.len() {
-            let mut is_valid = true;
-            for x in 0..self.map[y].len() {
-                if !pred(&self.map[x][y]) {
-                    is_valid = false;
-                    break;
-                }
-            }
-            if is_valid { cols.push(y as i64); }
-        }
-        cols
+    pub fn filter_cols(&self, pred: fn(&char) -> bool) -> Vec<i64> {
+        (0..self.width())
+            .filter(|&col| self.map.iter().all(|row| pred(&row[col])))
+            .map(|col| col as i64)
+            .collect_vec()
     }
 
-    pub(crate) fn find_all(&self, pred: fn(&char) -> bool) -> Vec<Point> {
+    pub fn find_all(&self, pred: fn(&char) -> bool) -> Vec<Point> {
         let mut points: Vec<Point>= vec![];
         for (y, line) in self.map.iter().enumerate() {
             for (x, c) in line.iter().enumerate() {
@@ -206,14 +189,32 @@ impl CharMap {
         points
     }
 
-    pub(crate) fn rotate_clockwise(&mut self) {
+    pub fn rotate_clockwise(&mut self) {
         self.transpose();
         (0..self.height()).for_each(|i| self.map[i].reverse());
     }
 
-    pub(super) fn transpose(&mut self) {
+    pub fn transpose(&mut self) {
         self.map = (0..self.map[0].len())
-            .map(|i| self.map.iter().map(|inner| inner[i].clone()).collect::<Vec<char>>())
+            .map(|i| self.map.iter().map(|inner| inner[i]).collect::<Vec<char>>())
             .collect();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::util::{CharMap, Point};
+
+    #[test]
+    fn char_map() {
+        let map = CharMap::parse("#..\n#.#\n").unwrap();
+        assert_eq!((map.width(), map.height()), (3, 2));
+        assert_eq!(map.at(&Point { x: 2, y: 1 }), Some('#'));
+        assert_eq!(map.at(&Point { x: 2, y: 2 }), None);
+        assert_eq!(map.at(&Point { x: -1, y: 0 }), None);
+        assert_eq!(map.filter_cols(|&c| c == '#'), vec![0]);
+        assert_eq!(map.filter_rows(|&c| c == '.'), Vec::<i64>::new());
+        assert_eq!(map.find_all(|&c| c == '#'), vec![Point { x: 0, y: 0 }, Point { x: 0, y: 1 }, Point { x: 2, y: 1 }]);
+        assert!(CharMap::parse("#.\n\n#.").is_none());
+    }
+}
@@ -0,0 +1,119 @@
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+const NUMBERS: &[&str] = &["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+struct Calibration{ val: u64 }
+
+impl Calibration {
+    /// `None` if the line has no digit at all.
+    pub fn new(line: &str, consider_text: bool) -> Option<Self> {
+        let(first, last) = (0..line.len()).fold((0u64, 0u64), | (mut first, mut last), start| {
+            let mut digit = if line.chars().nth(start).unwrap_or('-').is_numeric() {
+                line.chars().nth(start).unwrap_or('-').to_digit(10).unwrap_or(0) as u64
+            } else { 0 };
+            if consider_text {
+                for (i, num) in NUMBERS.iter().enumerate() {
+                    if line[start..line.len()].starts_with(num) { digit = (i as u64) + 1; }
+                }
+            }
+            if digit != 0 {
+                last = digit;
+                if first == 0 { first = last; }
+            }
+            (first, last)
+        });
+        (first != 0).then_some(Calibration { val: first * 10 + last })
+    }
+}
+
+fn sum(lines: &[String], consider_text: bool) -> Result<PuzzleResult, AocError> {
+    let mut sum = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let Some(calibration) = Calibration::new(line, consider_text) else {
+            return Err(AocError::unsolvable(Day01::DAY, format!("line {} has no digit", i + 1)));
+        };
+        sum += calibration.val;
+    }
+    Ok(PuzzleResult::from(sum))
+}
+
+pub struct Day01;
+
+impl Puzzle for Day01 {
+    type Input = Vec<String>;
+
+    const DAY: u8 = 1;
+
+    const TITLE: &'static str = "Trebuchet?!";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        lines(input)
+            .map(|(line_nr, line)| {
+                let mut scanner = Scanner::new(Self::DAY, line_nr, line);
+                let line = scanner.token("a letter or a digit", |c| c.is_ascii_lowercase() || c.is_ascii_digit())?;
+                scanner.end()?;
+                Ok(String::from(line))
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Result<PuzzleResult, AocError> { sum(lines, false) }
+
+    fn part2(lines: &Self::Input) -> Result<PuzzleResult, AocError> { sum(lines, true) }
+
+    /// `size` lines of letters with some digits and spelled out digits among them, at least one digit each.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut line = String::new();
+            for _ in 0..rng.range(1..=12) {
+                match rng.below(10) {
+                    0 | 1 => line.push_str(rng.pick::<&str>(NUMBERS)),
+                    2 | 3 => line.push(char::from(b'1' + rng.below(9) as u8)),
+                    _ => line.push(char::from(b'a' + rng.below(26) as u8)),
+                }
+            }
+            if !line.contains(|c: char| c.is_ascii_digit()) {
+                line.insert(rng.range(0..=line.len()), char::from(b'1' + rng.below(9) as u8));
+            }
+            input += &line;
+            input += "\n";
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day01::Day01;
+    use crate::error::AocError;
+
+    const INPUT_1: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+    const INPUT_2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn part1() { assert_eq!(Day01.part1(INPUT_1), Ok(PuzzleResult::Number(142))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day01.part2(INPUT_2), Ok(PuzzleResult::Number(281))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day01.part1("1abc2\n\ntreb7uchet\n"), Err(AocError::parse_at(1, 2, 1, "expected a letter or a digit, found the end of the line")));
+        assert_eq!(Day01.part1("1abc2\npqr3 stu8vwx"), Err(AocError::parse_at(1, 2, 5, "expected the end of the line, found ` `")));
+        assert_eq!(Day01.part1("1abc2\nabc"), Err(AocError::unsolvable(1, "line 2 has no digit")));
+    }
+}
\ No newline at end of file
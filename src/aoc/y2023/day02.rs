@@ -0,0 +1,104 @@
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+pub struct Game {
+    id: u64,
+    red: Vec<u64>,
+    green: Vec<u64>,
+    blue: Vec<u64>,
+}
+
+impl Game {
+    /// `Game ID: COUNT COLOR, COUNT COLOR; ...`
+    pub fn new(line_nr: usize, line: &str) -> Result<Self, AocError> {
+        let mut scanner = Scanner::new(Day02::DAY, line_nr, line);
+        scanner.expect("Game ")?;
+        let id = scanner.number()?;
+        scanner.expect(":")?;
+        let mut game = Game { id, red: vec![], green: vec![], blue: vec![] };
+        loop {
+            scanner.expect(" ")?;
+            let count = scanner.number()?;
+            scanner.expect(" ")?;
+            match scanner.one_of(&["red", "green", "blue"])? {
+                "red" => game.red.push(count),
+                "green" => game.green.push(count),
+                _ => game.blue.push(count),
+            }
+            if !scanner.eat(",") && !scanner.eat(";") {
+                scanner.end()?;
+                return Ok(game);
+            }
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Puzzle for Day02 {
+    type Input = Vec<Game>;
+
+    const DAY: u8 = 2;
+
+    const TITLE: &'static str = "Cube Conundrum";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> { lines(input).map(|(line_nr, line)| Game::new(line_nr, line)).collect() }
+
+    fn part1(games: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(games.iter()
+            .filter(|g| g.red.iter().all(|&x| x <= 12) && g.green.iter().all(|&x| x <= 13) && g.blue.iter().all(|&x| x <= 14))
+            .map(|g| g.id)
+            .sum::<u64>()))
+    }
+
+    fn part2(games: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(games.iter()
+            .map(|g| g.red.iter().max().unwrap_or(&0) * g.green.iter().max().unwrap_or(&0) * g.blue.iter().max().unwrap_or(&0))
+            .sum::<u64>()))
+    }
+
+    /// `size` games of up to six draws of up to 20 cubes of each color.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for id in 1..=size {
+            let mut draws = vec![];
+            for _ in 0..rng.range(1..=6) {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let cubes = colors[..rng.range(1..=3)].iter().map(|color| format!("{} {color}", rng.range(1..=20)));
+                draws.push(cubes.collect::<Vec<String>>().join(", "));
+            }
+            input += &format!("Game {id}: {}\n", draws.join("; "));
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day02::Day02;
+    use crate::error::AocError;
+
+    const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn part1() { assert_eq!(Day02.part1(INPUT), Ok(PuzzleResult::Number(8))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day02.part2(INPUT), Ok(PuzzleResult::Number(2286))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day02.part1("Game 1: 3 blue; 4 rd"), Err(AocError::parse_at(2, 1, 19, "expected `red`, `green` or `blue`, found `rd`")));
+        assert_eq!(Day02.part1("Game 1: x blue"), Err(AocError::parse_at(2, 1, 9, "expected a number, found `x`")));
+        assert_eq!(Day02.part1("Game 1: 3 blue,"), Err(AocError::parse_at(2, 1, 16, "expected ` `, found the end of the line")));
+        assert_eq!(Day02.part1("Game 1: 3 blue\nGame 2"), Err(AocError::parse_at(2, 2, 7, "expected `:`, found the end of the line")));
+    }
+}
\ No newline at end of file
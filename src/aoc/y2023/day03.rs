@@ -0,0 +1,144 @@
+use std::ops::{AddAssign, Range};
+use itertools::Itertools;
+use regex::{Match, Regex};
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::{text, Rng};
+use crate::aoc::input::{grid, lines};
+use crate::error::AocError;
+
+const GEAR_RE: &str = r"[*]";
+const NUMBER_RE: &str = r"[0-9]+";
+const SYMBOL_RE: &str = r"[^0-9.]";
+
+pub struct Schematics {
+    lines: Vec<String>,
+}
+
+impl Schematics {
+    pub fn new(input: &str) -> Self {
+        let mut schematics = Schematics { lines: vec![] };
+        schematics.lines.extend(input.split('\n').map(|x| x.into()));
+        schematics
+    }
+
+    pub fn fold<F, T: AddAssign + Copy>(&self, re: &str, init: T,  mut f: F) -> T where F: FnMut(T, usize, &Match) -> T {
+        let mut acc = init;
+        let mut line_nr = 0;
+        let reg = Regex::new(re).unwrap();
+        self.lines.iter().for_each(|line| {
+            reg.find_iter(line).for_each(|m| acc = f(acc, line_nr, &m) );
+            line_nr += 1;
+        });
+        acc
+    }
+
+    pub fn find_neighbours(&self, re: &str, line_nr: usize, m: &Match) -> Vec<Match<'_>> {
+        let reg = Regex::new(re).unwrap();
+        let mut matches = reg.find_iter(self.lines[line_nr].as_str()).collect_vec();
+        if line_nr > 0 { matches.extend(reg.find_iter(self.lines[line_nr - 1].as_str())); }
+        if line_nr < self.lines.len() - 1 { matches.extend(reg.find_iter(self.lines[line_nr + 1].as_str())); }
+        matches.iter().filter(|x| self.all_neighbours(line_nr, x).any(|i| m.start() <= i && i < m.end())).copied().collect()
+    }
+
+    fn all_neighbours(&self, line_nr: usize, m: &Match) -> Range<usize> {
+        Range {
+            start: m.start().saturating_sub(1),
+            end: (m.end() + 1).min(self.lines[line_nr].as_str().chars().count()),
+        }
+    }
+}
+pub struct Day03;
+
+impl Puzzle for Day03 {
+    type Input = Schematics;
+
+    const DAY: u8 = 3;
+
+    const TITLE: &'static str = "Gear Ratios";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        grid(Self::DAY, &lines(input).collect_vec(), "a digit, `.` or a symbol", |c| c.is_ascii_graphic())?;
+        Ok(Schematics::new(input.trim_end()))
+    }
+
+    fn part1(schematics: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(schematics.fold(NUMBER_RE, 0, |mut sum, line_nr, m| {
+            let matches = schematics.find_neighbours(SYMBOL_RE, line_nr, m);
+            if !matches.is_empty() {
+                sum += m.as_str().parse::<u64>().unwrap_or(0);
+            }
+            sum
+        })))
+    }
+
+    fn part2(schematics: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(schematics.fold(GEAR_RE, 0, |mut sum, line_nr, m| {
+            let matches = schematics.find_neighbours(NUMBER_RE, line_nr, m);
+            if matches.len() == 2 {
+                sum += matches.first().unwrap().as_str().parse::<u64>().unwrap_or(0) *
+                    matches.get(1).unwrap().as_str().parse::<u64>().unwrap_or(0);
+            }
+            sum
+        })))
+    }
+
+    /// A `size` by `size` schematic of numbers with symbols scattered between them, gears more often than the others.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut rows = vec![];
+        for _ in 0..size {
+            let mut row = vec![];
+            while row.len() < size {
+                if rng.chance(0.15) {
+                    let len = rng.range(1..=3).min(size - row.len());
+                    row.push(char::from(b'1' + rng.below(9) as u8));
+                    row.extend((1..len).map(|_| char::from(b'0' + rng.below(10) as u8)));
+                    if row.len() < size {
+                        row.push('.');
+                    }
+                } else if rng.chance(0.1) {
+                    row.push(*rng.pick(&['*', '*', '*', '#', '+', '$', '/', '@', '=', '%', '&', '-']));
+                } else {
+                    row.push('.');
+                }
+            }
+            rows.push(row);
+        }
+        Some(text(&rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day03::Day03;
+    use crate::error::AocError;
+
+    const INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn part1() { assert_eq!(Day03.part1(INPUT), Ok(PuzzleResult::Number(4361))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day03.part2(INPUT), Ok(PuzzleResult::Number(467835))); }
+
+    #[test]
+    fn narrow() {
+        assert_eq!(Day03.part1("5\n*\n"), Ok(PuzzleResult::Number(5)));
+        assert_eq!(Day03.part2("5\n*\n"), Ok(PuzzleResult::Number(0)));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day03.part1("467..\n...*\n"), Err(AocError::parse_at(3, 2, 5, "expected a digit, `.` or a symbol, found the end of the line")));
+        assert_eq!(Day03.part1("467..\n.. *.\n"), Err(AocError::parse_at(3, 2, 3, "expected a digit, `.` or a symbol, found ` `")));
+    }
+}
\ No newline at end of file
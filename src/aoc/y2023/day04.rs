@@ -0,0 +1,120 @@
+use std::collections::VecDeque;
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+pub struct Card {
+    winning: Vec<u64>,
+    actual: Vec<u64>,
+}
+
+impl Card {
+    /// `Card ID: WINNING NUMBERS | NUMBERS`, the numbers are aligned with extra spaces.
+    pub fn new(line_nr: usize, line: &str) -> Result<Self, AocError> {
+        let mut scanner = Scanner::new(Day04::DAY, line_nr, line);
+        scanner.expect("Card ")?;
+        scanner.spaces();
+        scanner.number::<u64>()?;
+        scanner.expect(":")?;
+        scanner.spaces();
+        let winning = scanner.spaced_numbers()?;
+        scanner.expect("|")?;
+        scanner.spaces();
+        let actual = scanner.spaced_numbers()?;
+        scanner.end()?;
+        Ok(Card { winning, actual })
+    }
+
+    pub fn geometric_points(&self) -> u64 {
+        self.count_matches().checked_sub(1).map(|x| u64::pow(2, x as u32)).unwrap_or(0)
+    }
+
+    pub fn count_matches(&self) -> usize {
+        self.winning.iter().filter(|x| self.actual.contains(x)).count()
+    }
+}
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    type Input = Vec<Card>;
+
+    const DAY: u8 = 4;
+
+    const TITLE: &'static str = "Scratchcards";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> { lines(input).map(|(line_nr, line)| Card::new(line_nr, line)).collect() }
+
+    fn part1(cards: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(cards.iter()
+            .map(|card| card.geometric_points())
+            .sum::<u64>()))
+    }
+
+    fn part2(cards: &Self::Input) -> Result<PuzzleResult, AocError> {
+        let mut solved = VecDeque::<u64>::new(); // for dynamic programing
+        for (i, card) in cards.iter().enumerate().rev() {
+            let count = card.count_matches();
+            if count > solved.len() {
+                return Err(AocError::unsolvable(Self::DAY, format!("card {} wins copies of cards past the last one", i + 1)));
+            }
+            solved.push_front(1 + (0..count).map(|i| solved[i]).sum::<u64>());
+        }
+        Ok(PuzzleResult::from(solved.iter().sum::<u64>()))
+    }
+
+    /// `size` cards of ten winning numbers and 25 numbers. The matches are drawn from the last card to the first, so
+    /// that no card wins copies past the last one or more than a million copies in all.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut matches = vec![];
+        let mut copies = VecDeque::<u64>::new();
+        for _ in 0..size {
+            let mut count = rng.range(0..=copies.len().min(10));
+            while (0..count).map(|i| copies[i]).sum::<u64>() >= 1_000_000 {
+                count -= 1;
+            }
+            copies.push_front(1 + (0..count).map(|i| copies[i]).sum::<u64>());
+            matches.push(count);
+        }
+        let width = size.to_string().len();
+        let numbers = |numbers: &[u64]| numbers.iter().map(|x| format!("{x:>2}")).collect::<Vec<String>>().join(" ");
+        let mut input = String::new();
+        for (id, count) in matches.into_iter().rev().enumerate() {
+            let mut pool = (1..100).collect::<Vec<u64>>();
+            rng.shuffle(&mut pool);
+            let (winning, others) = pool.split_at(10);
+            let mut actual = [&winning[..count], &others[..25 - count]].concat();
+            rng.shuffle(&mut actual);
+            input += &format!("Card {:>width$}: {} | {}\n", id + 1, numbers(winning), numbers(&actual));
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day04::Day04;
+    use crate::error::AocError;
+
+    const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn part1() { assert_eq!(Day04.part1(INPUT), Ok(PuzzleResult::Number(13))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day04.part2(INPUT), Ok(PuzzleResult::Number(30))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day04.part1("Card 1: 41 48 | 83 x6"), Err(AocError::parse_at(4, 1, 20, "expected the end of the line, found `x`")));
+        assert_eq!(Day04.part1("Card 1: 41 48 83"), Err(AocError::parse_at(4, 1, 17, "expected `|`, found the end of the line")));
+        assert_eq!(Day04.part2("Card 1: 41 48 | 48 41"), Err(AocError::unsolvable(4, "card 1 wins copies of cards past the last one")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,268 @@
+use std::iter::Peekable;
+use std::ops::{Not, Range};
+use rayon::prelude::*;
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+type RangeValue = f64;
+type SeedValue = u64;
+
+/// The maps of the almanac, in the order in which they are given and applied.
+const MAPS: [&str; 7] = [
+    "seed-to-soil", "soil-to-fertilizer", "fertilizer-to-water", "water-to-light", "light-to-temperature",
+    "temperature-to-humidity", "humidity-to-location",
+];
+
+struct MapEntry {
+    source: Range<RangeValue>,
+    destination: Range<RangeValue>,
+}
+
+impl MapEntry {
+    /// `DESTINATION SOURCE LENGTH`
+    pub fn new(line_nr: usize, line: &str) -> Result<Self, AocError> {
+        let mut scanner = Scanner::new(Day05::DAY, line_nr, line);
+        let destination = scanner.number::<SeedValue>()?;
+        scanner.expect(" ")?;
+        let source = scanner.number::<SeedValue>()?;
+        scanner.expect(" ")?;
+        let column = scanner.column();
+        let length = scanner.number::<SeedValue>()?;
+        scanner.end()?;
+        if source.max(destination).checked_add(length).is_none() {
+            return Err(scanner.error_at(column, "the range does not fit in 64 bits"));
+        }
+        Ok(MapEntry {
+            source: Range { start: source as RangeValue, end: (source + length ) as RangeValue },
+            destination: Range { start: destination as RangeValue, end: (destination + length ) as RangeValue },
+        })
+    }
+
+    pub fn contains(&self, input: RangeValue) -> bool {
+        self.source.contains(&input)
+    }
+
+    pub fn convert(&self, x: RangeValue) -> RangeValue {
+        if self.contains(x).not() {
+            x
+        } else {
+            self.destination.start + (x - self.source.start) * (self.destination.end - self.destination.start) / (self.source.end - self.source.start)
+        }
+    }
+}
+
+type MapEntries = Vec<MapEntry>;
+
+struct Map {
+    entries: MapEntries
+}
+
+impl Map {
+
+    pub fn convert(&self, input: RangeValue) -> RangeValue {
+        for entry in self.entries.iter() {
+            if entry.contains(input) { return entry.convert(input); }
+        }
+        input
+    }
+}
+
+pub struct Alamac {
+    maps: Vec<Map>,
+}
+
+impl Alamac {
+    /// The maps following the seeds, each after an empty line and its `NAME map:` header.
+    pub fn new<'a>(lines: &mut Peekable<impl Iterator<Item = (usize, &'a str)>>) -> Result<Self, AocError> {
+        let mut maps = vec![];
+        let mut line_nr = 1;
+        for name in MAPS {
+            let header = format!("{name} map:");
+            line_nr += 1;
+            match lines.next() {
+                Some((_, "")) => {}
+                Some((_, line)) => return Err(Scanner::new(Day05::DAY, line_nr, line).expected("an empty line")),
+                None => return Err(AocError::parse(Day05::DAY, line_nr, format!("expected an empty line and `{header}`"))),
+            }
+            line_nr += 1;
+            let Some((_, line)) = lines.next() else {
+                return Err(AocError::parse(Day05::DAY, line_nr, format!("expected `{header}`")));
+            };
+            let mut scanner = Scanner::new(Day05::DAY, line_nr, line);
+            scanner.expect(&header)?;
+            scanner.end()?;
+            let mut entries = vec![];
+            while let Some((_, line)) = lines.next_if(|(_, line)| !line.is_empty()) {
+                line_nr += 1;
+                entries.push(MapEntry::new(line_nr, line)?);
+            }
+            if entries.is_empty() {
+                return Err(AocError::parse(Day05::DAY, line_nr + 1, "expected `DESTINATION SOURCE LENGTH`"));
+            }
+            maps.push(Map { entries });
+        }
+        Ok(Alamac { maps })
+    }
+
+    pub fn get_location(&self, seed: SeedValue) -> SeedValue {
+        self.maps.iter().fold(seed as RangeValue, |value, map| map.convert(value)) as SeedValue
+    }
+}
+
+pub struct ValueSeeds {
+    seeds: Vec<SeedValue>
+}
+
+impl ValueSeeds {
+    /// `seeds: SEED SEED ...`
+    pub fn new(line: &str) -> Result<Self, AocError> {
+        let mut scanner = Scanner::new(Day05::DAY, 1, line);
+        scanner.expect("seeds:")?;
+        scanner.spaces();
+        let seeds = scanner.spaced_numbers()?;
+        scanner.end()?;
+        Ok(ValueSeeds { seeds })
+    }
+}
+
+struct RangeSeeds {
+    seeds: Vec<Range<SeedValue>>
+}
+
+impl RangeSeeds {
+    /// The seed values read as pairs of a start and a length.
+    pub fn new(values: &ValueSeeds) -> Result<Self, AocError> {
+        if values.seeds.len() % 2 == 1 {
+            return Err(AocError::parse(Day05::DAY, 1, "expected pairs of a start and a length, found an odd number of values"));
+        }
+        let seeds = values.seeds
+            .chunks_exact(2)
+            .map(|pair| match pair[0].checked_add(pair[1]) {
+                Some(end) => Ok(Range { start: pair[0], end }),
+                None => Err(AocError::parse(Day05::DAY, 1, format!("the range {} {} does not fit in 64 bits", pair[0], pair[1]))),
+            })
+            .collect::<Result<Vec<Range<SeedValue>>, AocError>>()?;
+        Ok(RangeSeeds { seeds })
+    }
+}
+
+pub struct Day05;
+
+impl Puzzle for Day05 {
+    type Input = (ValueSeeds, Alamac);
+
+    const DAY: u8 = 5;
+
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut lines = lines(input).peekable();
+        let seeds = ValueSeeds::new(lines.next().map_or("", |(_, line)| line))?;
+        let alamac = Alamac::new(&mut lines)?;
+        if let Some((line_nr, _)) = lines.find(|(_, line)| !line.is_empty()) {
+            return Err(AocError::parse(Self::DAY, line_nr, "expected the end of the input after the last map"));
+        }
+        Ok((seeds, alamac))
+    }
+
+    fn part1((seeds, alamac): &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(seeds.seeds.par_iter()
+            .map(|&x| alamac.get_location(x))
+            .min()
+            .ok_or_else(|| AocError::unsolvable(Self::DAY, "there are no seeds"))?))
+    }
+
+    fn part2((seeds, alamac): &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(RangeSeeds::new(seeds)?.seeds.into_par_iter()
+            .flat_map(|range| range.clone().into_par_iter().map(|i| alamac.get_location(i)))
+            .min()
+            .ok_or_else(|| AocError::unsolvable(Self::DAY, "every seed range is empty"))?))
+    }
+
+    /// Maps of up to `size` entries with 32-bit values, and seed ranges of about `size` thousand seeds in all since
+    /// part 2 walks every one of them.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const VALUES: u64 = 1 << 32;
+        let pairs = (size / 10).clamp(1, 10) as u64;
+        let mut seeds = vec![];
+        for _ in 0..pairs {
+            let length = rng.below(2000 * size as u64 / pairs) + 1;
+            seeds.extend([rng.below(VALUES - length).to_string(), length.to_string()]);
+        }
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+        for name in MAPS {
+            let mut starts = (0..size).map(|_| rng.below(VALUES)).collect::<Vec<u64>>();
+            starts.sort_unstable();
+            starts.dedup();
+            let ends = starts.iter().skip(1).copied().chain([VALUES]).collect::<Vec<u64>>();
+            let mut entries = starts.iter().zip(ends)
+                .map(|(&start, end)| {
+                    let length = rng.below(end - start) + 1;
+                    format!("{} {start} {length}\n", rng.below(VALUES - length))
+                })
+                .collect::<Vec<String>>();
+            rng.shuffle(&mut entries);
+            input += &format!("\n{name} map:\n{}", entries.concat());
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day05::Day05;
+    use crate::error::AocError;
+
+    const INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn part1() { assert_eq!(Day05.part1(INPUT), Ok(PuzzleResult::Number(35))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day05.part2(INPUT), Ok(PuzzleResult::Number(46))); } // too high 6082853
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day05.part1(&INPUT.replace("52 50 48", "52 50")), Err(AocError::parse_at(5, 5, 6, "expected ` `, found the end of the line")));
+        let missing_line = INPUT.replace("\nwater-to-light map:", "water-to-light map:");
+        assert_eq!(Day05.part1(&missing_line), Err(AocError::parse_at(5, 17, 1, "expected a number, found `w`")));
+        let truncated = &INPUT[..INPUT.find("\n\nhumidity").unwrap()];
+        assert_eq!(Day05.part1(truncated), Err(AocError::parse(5, 30, "expected an empty line and `humidity-to-location map:`")));
+        assert_eq!(Day05.part2(&INPUT.replace("55 13", "55")), Err(AocError::parse(5, 1, "expected pairs of a start and a length, found an odd number of values")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,106 @@
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+pub struct Race {
+    duration: u64,
+    distance: u64,
+}
+
+impl Race {
+    pub fn winning_combinations_count(&self) -> u64 {
+        (1..self.duration.saturating_sub(1))
+            .filter(|x| (self.duration - x) * x > self.distance)
+            .count() as u64
+    }
+}
+
+/// Numbers following `{label}:` on the given (1-based) line.
+fn numbers(line: Option<(usize, &str)>, label: &str, line_nr: usize) -> Result<Vec<u64>, AocError> {
+    let Some((_, line)) = line else {
+        return Err(AocError::parse(Day06::DAY, line_nr, format!("expected `{label}:`")));
+    };
+    let mut scanner = Scanner::new(Day06::DAY, line_nr, line);
+    scanner.expect(&format!("{label}:"))?;
+    scanner.spaces();
+    let values = scanner.spaced_numbers()?;
+    scanner.end()?;
+    Ok(values)
+}
+
+pub struct Day06;
+
+impl Puzzle for Day06 {
+    type Input = Vec<Race>;
+
+    const DAY: u8 = 6;
+
+    const TITLE: &'static str = "Wait For It";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut lines = lines(input);
+        let times = numbers(lines.next(), "Time", 1)?;
+        let distances = numbers(lines.next(), "Distance", 2)?;
+        if times.len() != distances.len() {
+            return Err(AocError::parse(Self::DAY, 2, "expected as many distances as times"));
+        }
+        if let Some((line_nr, _)) = lines.find(|(_, line)| !line.is_empty()) {
+            return Err(AocError::parse(Self::DAY, line_nr, "expected the end of the input after the distances"));
+        }
+        Ok(times.into_iter().zip(distances).map(|(duration, distance)| Race { duration, distance }).collect())
+    }
+
+    fn part1(races: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(races.iter().map(Race::winning_combinations_count).product::<u64>()))
+    }
+
+    fn part2(races: &Self::Input) -> Result<PuzzleResult, AocError> {
+        // the spaces between the numbers are bad kerning, so there is a single race
+        let concat = |f: fn(&Race) -> u64| races.iter().map(|race| f(race).to_string()).collect::<String>();
+        let duration = concat(|race| race.duration).parse::<u64>()
+            .map_err(|err| AocError::parse(Self::DAY, 1, err.to_string()))?;
+        let distance = concat(|race| race.distance).parse::<u64>()
+            .map_err(|err| AocError::parse(Self::DAY, 2, err.to_string()))?;
+        Ok(PuzzleResult::from(Race { duration, distance }.winning_combinations_count()))
+    }
+
+    /// Races that can each be won, one more for every 25 of `size` up to the four of the puzzle. Part 2 counts through
+    /// the digits of all durations put together, so more would take ages.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut times = String::from("Time:    ");
+        let mut distances = String::from("Distance:");
+        for _ in 0..(size / 25 + 1).min(4) {
+            let duration = rng.range(7..=99) as u64;
+            let distance = rng.below(duration / 2 * (duration - duration / 2));
+            let width = distance.to_string().len().max(duration.to_string().len()) + 2;
+            times += &format!("{duration:>width$}");
+            distances += &format!("{distance:>width$}");
+        }
+        Some(format!("{times}\n{distances}\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day06::Day06;
+    use crate::error::AocError;
+
+    const INPUT: &str = "Time:      7  15   30\nDistance:  9  40  200";
+
+    #[test]
+    fn part1() { assert_eq!(Day06.part1(INPUT), Ok(PuzzleResult::Number(288))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day06.part2(INPUT), Ok(PuzzleResult::Number(71503))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day06.part1("Time: 7 15\n"), Err(AocError::parse(6, 2, "expected `Distance:`")));
+        assert_eq!(Day06.part1("Time: 7 x\nDistance: 9 40"), Err(AocError::parse_at(6, 1, 9, "expected the end of the line, found `x`")));
+        assert_eq!(Day06.part1("Time: 7\nDistanc: 9"), Err(AocError::parse_at(6, 2, 1, "expected `Distance:`, found `Distanc: `")));
+        assert_eq!(Day06.part1("Time:\nDistance: 9"), Err(AocError::parse_at(6, 1, 6, "expected a number, found the end of the line")));
+        assert_eq!(Day06.part1("Time: 7 15\nDistance: 9"), Err(AocError::parse(6, 2, "expected as many distances as times")));
+    }
+}
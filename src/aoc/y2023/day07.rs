@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+use itertools::Itertools;
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+enum HandType {
+    HighCard = 0,
+    OnePair = 1,
+    TwoPairs = 2,
+    ThreeOfAKind = 3,
+    FullHouse = 4,
+    FourOfAKind = 5,
+    FiveOfAKind = 6,
+}
+
+impl HandType {
+    pub fn new(cards: [char; 5]) -> Self {
+        let mut card_matches: std::collections::HashMap<char, i32> = std::collections::HashMap::new();
+        cards.iter().for_each(|x| *card_matches.entry(*x).or_insert(0) += 1);
+        match card_matches.len() {
+            1 => HandType::FiveOfAKind,
+            2 if card_matches.values().any(|&x| x == 4) => HandType::FourOfAKind,
+            2 if card_matches.values().any(|&x| x == 3) => HandType::FullHouse,
+            3 if card_matches.values().any(|&x| x == 3) => HandType::ThreeOfAKind,
+            3 if card_matches.values().any(|&x| x == 2) => HandType::TwoPairs,
+            4 => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+pub struct Hand {
+    cards: [char; 5],
+    bid: u64,
+}
+
+impl Hand {
+    /// `CARDS BID` with five cards.
+    pub fn new(line_nr: usize, line: &str) -> Result<Self, AocError> {
+        let mut scanner = Scanner::new(Day07::DAY, line_nr, line);
+        let mut cards = ['2'; 5];
+        for card in cards.iter_mut() {
+            *card = scanner.char("a card", |c| CARDS.contains(&c))?;
+        }
+        scanner.expect(" ")?;
+        let bid = scanner.number()?;
+        scanner.end()?;
+        Ok(Hand { cards, bid })
+    }
+
+    fn hand_type(&self, joker: bool) -> HandType {
+        if joker && self.cards.contains(&'J') {
+            CARDS.iter().copied().map(|c| HandType::new(self.cards.map(|x| if x == 'J' { c } else { x }))).max().unwrap_or(HandType::HighCard)
+        } else {
+            HandType::new(self.cards)
+        }
+    }
+}
+
+fn card_value(card: char, joker: bool) -> u8 {
+    match card {
+        '0'..='9' => card.to_digit(10).unwrap() as u8,
+        'T' => 10,
+        'J' => if joker { 0 } else { 11 },
+        'Q' => 12,
+        'K' => 13,
+        'A' => 14,
+        _ => unreachable!(),
+    }
+}
+
+fn cmp((a_type, a): &(HandType, &Hand), (b_type, b): &(HandType, &Hand), joker: bool) -> Ordering {
+    if a_type != b_type { return a_type.cmp(b_type); }
+    for (i, &card) in a.cards.iter().enumerate() {
+        if card_value(card, joker) != card_value(b.cards[i], joker) {
+            return card_value(card, joker).cmp(&card_value(b.cards[i], joker));
+        }
+    }
+    Ordering::Equal
+}
+
+fn solve(hands: &[Hand], jack: bool) -> u64 {
+    hands.iter()
+        .map(|hand| (hand.hand_type(jack), hand))
+        .sorted_by(|a, b| cmp(a, b, jack))
+        .enumerate()
+        .map(|(i, (_, hand))| hand.bid * (i + 1) as u64)
+        .sum()
+}
+
+pub struct Day07;
+
+impl Puzzle for Day07 {
+    type Input = Vec<Hand>;
+
+    const DAY: u8 = 7;
+
+    const TITLE: &'static str = "Camel Cards";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> { lines(input).map(|(line_nr, line)| Hand::new(line_nr, line)).collect() }
+
+    fn part1(hands: &Self::Input) -> Result<PuzzleResult, AocError> { Ok(PuzzleResult::from(solve(hands, false))) }
+
+    fn part2(hands: &Self::Input) -> Result<PuzzleResult, AocError> { Ok(PuzzleResult::from(solve(hands, true))) }
+
+    /// `size` hands of random cards with bids up to 1000.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size {
+            let cards = (0..5).map(|_| *rng.pick(&CARDS)).collect::<String>();
+            input += &format!("{cards} {}\n", rng.range(1..=1000));
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day07::Day07;
+    use crate::error::AocError;
+
+    const INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn part1() { assert_eq!(Day07.part1(INPUT), Ok(PuzzleResult::Number(6440))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day07.part2(INPUT), Ok(PuzzleResult::Number(5905))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day07.part1("32T3K 765\nT55J 684"), Err(AocError::parse_at(7, 2, 5, "expected a card, found ` `")));
+        assert_eq!(Day07.part1("32T3K 765\nT55J1 684"), Err(AocError::parse_at(7, 2, 5, "expected a card, found `1`")));
+        assert_eq!(Day07.part1("32T3K765"), Err(AocError::parse_at(7, 1, 6, "expected ` `, found `7`")));
+    }
+}
@@ -0,0 +1,175 @@
+use std::string::String;
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+type Map = std::collections::HashMap<String, (String, String)>;
+
+#[derive(Debug)]
+pub struct Network {
+    instructions: Vec<char>,
+    map: Map,
+}
+
+impl Network {
+    /// The `L` and `R` instructions, an empty line and a `NODE = (LEFT, RIGHT)` line per node.
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let mut lines = lines(input);
+        let (_, line) = lines.next().unwrap_or((1, ""));
+        let mut scanner = Scanner::new(Day08::DAY, 1, line);
+        let instructions = scanner.token("`L` or `R`", |c| matches!(c, 'L' | 'R'))?.chars().collect();
+        scanner.end()?;
+        match lines.next() {
+            Some((_, "")) => {}
+            Some((line_nr, line)) => return Err(Scanner::new(Day08::DAY, line_nr, line).expected("an empty line")),
+            None => return Err(AocError::parse(Day08::DAY, 2, "expected an empty line followed by the nodes")),
+        }
+        let name = |scanner: &mut Scanner| scanner.token("a node", |c| c.is_ascii_alphanumeric()).map(String::from);
+        let mut map: Map = std::collections::HashMap::new();
+        for (line_nr, line) in lines {
+            let mut scanner = Scanner::new(Day08::DAY, line_nr, line);
+            let node = name(&mut scanner)?;
+            scanner.expect(" = (")?;
+            let left = name(&mut scanner)?;
+            scanner.expect(", ")?;
+            let right = name(&mut scanner)?;
+            scanner.expect(")")?;
+            scanner.end()?;
+            if map.insert(node.clone(), (left, right)).is_some() {
+                return Err(scanner.error_at(1, format!("node {node} is defined twice")));
+            }
+        }
+        if map.is_empty() {
+            return Err(AocError::parse(Day08::DAY, 3, "expected `NODE = (LEFT, RIGHT)`"));
+        }
+        Ok(Network { instructions, map })
+    }
+
+    pub fn count_steps(&self, start: String, end: fn(&str) -> bool) -> Result<u64, AocError> {
+        let node = |name: &str| self.map.get(name)
+            .cloned()
+            .ok_or_else(|| AocError::unsolvable(Day08::DAY, format!("missing node {name}")));
+        let mut current = (start.clone(), node(&start)?);
+        let mut steps = 0;
+        for direction in self.instructions.iter().cycle() {
+            let next = match direction {
+                'L' => current.1.0.clone(),
+                _ => current.1.1.clone(),
+            };
+            current = (next.clone(), node(&next)?);
+            steps += 1;
+            if end(current.0.as_str()) { break; }
+        }
+        Ok(steps)
+    }
+}
+
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    type Input = Network;
+
+    const DAY: u8 = 8;
+
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> { Network::new(input) }
+
+    fn part1(network: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(network.count_steps("AAA".into(), |x| x == "ZZZ")?))
+    }
+
+    fn part2(network: &Self::Input) -> Result<PuzzleResult, AocError> {
+        let steps = network.map
+            .keys()
+            .filter(|x| x.ends_with('A'))
+            .map(|start| network.count_steps(start.into(), |end| end.ends_with('Z')))
+            .collect::<Result<Vec<u64>, AocError>>()?;
+        Ok(PuzzleResult::from(steps.into_iter().fold(1, num::integer::lcm)))
+    }
+
+    /// `size` nodes or so on the paths of up to six ghosts, `AAA` to `ZZZ` being the first. On its path a ghost follows
+    /// the instructions from its `..A` node to its `..Z` node, the other side of every node leads anywhere.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const LETTERS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+        let instructions = (0..rng.range(1..=size.clamp(2, 300))).map(|_| *rng.pick(&['L', 'R'])).collect::<Vec<char>>();
+        let ghosts = (size / 20).clamp(1, 6);
+        let mut names = vec![String::from("AAA"), String::from("ZZZ")];
+        fn name(rng: &mut Rng, names: &mut Vec<String>, last: Option<u8>) -> usize {
+            loop {
+                let name = [*rng.pick(LETTERS), *rng.pick(LETTERS), last.unwrap_or_else(|| *rng.pick(LETTERS))];
+                let name = String::from_utf8_lossy(&name).into_owned();
+                if !names.contains(&name) {
+                    names.push(name);
+                    return names.len() - 1;
+                }
+            }
+        }
+        // the left and right node of every node, `None` where any node will do
+        let mut edges = vec![];
+        for ghost in 0..ghosts {
+            let (start, end) = match ghost {
+                0 => (0, 1),
+                _ => (name(rng, &mut names, Some(b'A')), name(rng, &mut names, Some(b'Z'))),
+            };
+            let mut path = vec![start];
+            path.extend((1..rng.range(1..=(size / ghosts).clamp(1, 1000))).map(|_| name(rng, &mut names, None)));
+            path.push(end);
+            edges.resize(names.len(), [None, None]);
+            for (step, nodes) in path.windows(2).enumerate() {
+                let side = usize::from(instructions[step % instructions.len()] == 'R');
+                edges[nodes[0]][side] = Some(nodes[1]);
+            }
+            edges[end] = edges[start];
+        }
+        let mut lines = edges.iter().enumerate()
+            .map(|(node, sides)| {
+                let [left, right] = sides.map(|side| &names[side.unwrap_or_else(|| rng.below(names.len() as u64) as usize)]);
+                format!("{} = ({left}, {right})\n", names[node])
+            })
+            .collect::<Vec<String>>();
+        rng.shuffle(&mut lines);
+        Some(format!("{}\n\n{}", instructions.iter().collect::<String>(), lines.concat()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day08::Day08;
+    use crate::error::AocError;
+
+    const INPUT1: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    const INPUT2: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn part1() { assert_eq!(Day08.part1(INPUT1), Ok(PuzzleResult::Number(6))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day08.part2(INPUT2), Ok(PuzzleResult::Number(6))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day08.part1("LLR\n\nAAA = (BBB, BBB)\nBBB = AAA"), Err(AocError::parse_at(8, 4, 4, "expected ` = (`, found ` = A`")));
+        assert_eq!(Day08.part1("LLR\n\nAAA = (BBB, BB-)"), Err(AocError::parse_at(8, 3, 15, "expected `)`, found `-`")));
+        assert_eq!(Day08.part1("LXR\n\nAAA = (AAA, AAA)"), Err(AocError::parse_at(8, 1, 2, "expected the end of the line, found `X`")));
+        assert_eq!(Day08.part1("LLR\nAAA = (AAA, AAA)"), Err(AocError::parse_at(8, 2, 1, "expected an empty line, found `A`")));
+        assert_eq!(Day08.part1("LLR\n\nAAA = (AAA, AAA)\nAAA = (BBB, BBB)"), Err(AocError::parse_at(8, 4, 1, "node AAA is defined twice")));
+        assert_eq!(Day08.part1("LLR\n\nBBB = (BBB, BBB)"), Err(AocError::unsolvable(8, "missing node AAA")));
+    }
+}
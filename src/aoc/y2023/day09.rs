@@ -0,0 +1,81 @@
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+fn extrapolate(numbers: &[i64], part2: bool) -> i64 {
+    if numbers.iter().all(|&x| x == 0i64) { return 0; }
+    let mut tmp: Vec<i64> = vec![];
+    for n in 1..numbers.len() {
+        tmp.push(numbers[n] - numbers[n-1]);
+    }
+    if part2 {
+        numbers.first().unwrap_or(&0i64) - extrapolate(&tmp, part2)
+    } else {
+        numbers.last().unwrap_or(&0i64) + extrapolate(&tmp, part2)
+    }
+}
+
+pub struct Day09;
+
+impl Puzzle for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    const DAY: u8 = 9;
+
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        lines(input)
+            .map(|(line_nr, line)| {
+                let mut scanner = Scanner::new(Self::DAY, line_nr, line);
+                let history = scanner.spaced_numbers()?;
+                scanner.end()?;
+                Ok(history)
+            })
+            .collect()
+    }
+
+    fn part1(histories: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::Number(histories.iter().map(|x| extrapolate(x, false)).sum()))
+    }
+
+    fn part2(histories: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::Number(histories.iter().map(|x| extrapolate(x, true)).sum()))
+    }
+
+    /// `size` histories of 21 values of a polynomial of a degree up to 5 with small coefficients.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size {
+            let coefficients = (0..rng.range(1..=6)).map(|_| rng.range(0..=20) as i64 - 10).collect::<Vec<i64>>();
+            let values = (0..21).map(|x| coefficients.iter().rev().fold(0, |value, c| value * x + c).to_string());
+            input += &values.collect::<Vec<String>>().join(" ");
+            input += "\n";
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day09::Day09;
+    use crate::error::AocError;
+
+    const INPUT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn part1() { assert_eq!(Day09.part1(INPUT), Ok(PuzzleResult::Number(114))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day09.part2(INPUT), Ok(PuzzleResult::Number(2))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day09.part1("0 3 6\n1 3 x"), Err(AocError::parse_at(9, 2, 5, "expected the end of the line, found `x`")));
+        assert_eq!(Day09.part1("0 3 6\n\n1 3 6"), Err(AocError::parse_at(9, 2, 1, "expected a number, found the end of the line")));
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::HashSet;
+use std::ops::Not;
+use itertools::Itertools;
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::{text, Rng};
+use crate::aoc::input::{grid, lines};
+use crate::error::AocError;
+
+type Map = Vec<Vec<char>>;
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy)]
+enum Direction { Left, Right, Up, Down }
+
+impl Direction {
+    fn is_valid_to(&self, c: char) -> bool {
+        match c {
+            '|' => *self == Direction::Up ||    *self == Direction::Down,
+            '-' => *self == Direction::Left  || *self == Direction::Right,
+            'L' => *self == Direction::Down ||  *self == Direction::Left,
+            'J' => *self == Direction::Down ||  *self == Direction::Right,
+            '7' => *self == Direction::Right || *self == Direction::Up,
+            'F' => *self == Direction::Up ||    *self == Direction::Left,
+            'S' => true,
+            _ => false
+        }
+    }
+
+    fn is_valid_from(&self, c: char) -> bool {
+        match c {
+            '|' => *self == Direction::Up ||    *self == Direction::Down,
+            '-' => *self == Direction::Left  || *self == Direction::Right,
+            'L' => *self == Direction::Up ||    *self == Direction::Right,
+            'J' => *self == Direction::Up ||    *self == Direction::Left,
+            '7' => *self == Direction::Left ||  *self == Direction::Down,
+            'F' => *self == Direction::Down ||  *self == Direction::Right,
+            'S' => true,
+            _ => false
+        }
+    }
+}
+
+const DIRECTIONS: [(i64, i64, Direction); 4] = [
+    (-1,  0,  Direction::Left),
+    ( 1,  0,  Direction::Right),
+    ( 0, -1,  Direction::Up),
+    ( 0,  1,  Direction::Down)
+];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct Point { x: i64, y: i64 }
+
+type Points = Vec<Point>;
+
+impl Point {
+    pub fn neighbours_in(&self, map: &Map) -> Points {
+        let mut points: Vec<Point> = vec![];
+        let c_curr = map[self.y as usize][self.x as usize];
+        for &(d_x, d_y, direction) in DIRECTIONS.iter().filter(|(_, _, c)| c.is_valid_from(c_curr)).clone() {
+            let y = self.y + d_y;
+            if y.is_negative() || y >= map.len() as i64 { continue }
+            let x = self.x + d_x;
+            if x.is_negative() || x >= map.first().unwrap().len() as i64 { continue }
+            if direction.is_valid_to(map[y as usize][x as usize]) {
+                points.push(Point { x, y });
+            }
+        }
+        points
+    }
+}
+
+#[derive(Clone)]
+struct Path {
+    points: Vec<Point>
+}
+
+impl Path {
+
+    pub fn new(start: Point) -> Self {
+        Path { points: vec![start] }
+    }
+
+    pub fn push(&mut self, point: Point) {
+        self.points.push(point)
+    }
+
+    pub fn is_cycle(&self) -> bool {
+        self.first().is_some_and(|s| self.last().is_some_and(|e| s == e)) && self.len() > 1
+    }
+
+    pub fn last(&self) -> Option<&Point> {
+        self.points.last()
+    }
+
+    pub fn first(&self) -> Option<&Point> {
+        self.points.first()
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+}
+
+fn find_start(chars: &Map, start: char) -> Option<Point> {
+    for (y, line) in chars.iter().enumerate() {
+        if let Some((x, _)) = line.iter().find_position(|&&x| x == start) {
+            return Some(Point { x: x as i64, y: y as i64 });
+        }
+    }
+    None
+}
+
+/// The loops through the start tile. A pipe connects to two tiles at most, so following it from a neighbour of the
+/// start either leads back to the start or ends. The search is iterative since a loop can run through most of the map.
+fn find_loops_from(map: &Map, start: char) -> Vec<Path> {
+    let Some(start) = find_start(map, start) else { return vec![]; };
+    let mut loops = vec![];
+    for first in start.neighbours_in(map) {
+        let mut path = Path::new(start);
+        let (mut previous, mut current) = (start, first);
+        loop {
+            path.push(current);
+            if current == start {
+                if path.is_cycle() && path.len() > 3 { loops.push(path); }
+                break;
+            }
+            let Some(next) = current.neighbours_in(map).into_iter().find(|&next| next != previous) else { break; };
+            (previous, current) = (current, next);
+        }
+    }
+    loops
+}
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    type Input = Map;
+
+    const DAY: u8 = 10;
+
+    const TITLE: &'static str = "Pipe Maze";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let lines = lines(input).collect_vec();
+        grid(Self::DAY, &lines, "a pipe, `.` or `S`", |c| "|-LJ7F.S".contains(c))?;
+        let starts = lines.iter()
+            .flat_map(|&(line_nr, line)| line.chars().positions(|c| c == 'S').map(move |x| (line_nr, x + 1)))
+            .collect_vec();
+        match starts[..] {
+            [] => return Err(AocError::parse(Self::DAY, 1, "missing the start tile `S`")),
+            [_, (line_nr, column), ..] => return Err(AocError::parse_at(Self::DAY, line_nr, column, "a second start tile `S`")),
+            [_] => {}
+        }
+        Ok(lines.iter().map(|(_, line)| line.chars().collect_vec()).collect_vec())
+    }
+
+    fn part1(map: &Self::Input) -> Result<PuzzleResult, AocError> {
+        let Some(cycle) = find_loops_from(map, 'S')
+            .into_iter()
+            .min_by_key(Path::len) else { return Err(AocError::unsolvable(Self::DAY, "the start tile is not on a loop")); };
+
+        Ok(PuzzleResult::from(cycle.len() / 2))
+    }
+
+    fn part2(map: &Self::Input) -> Result<PuzzleResult, AocError> {
+        let Some(cycle) = find_loops_from(map, 'S')
+            .into_iter()
+            .min_by_key(Path::len) else { return Err(AocError::unsolvable(Self::DAY, "the start tile is not on a loop")); };
+
+        let mut enclosed_tiles: HashSet<Point> = HashSet::new();
+        let on_loop = cycle.points.iter().copied().collect::<HashSet<Point>>();
+
+        for (y, row) in map.iter().enumerate() {
+            let mut is_inside = false;
+            for (x, &tile) in row.iter().enumerate() {
+                let p = Point { x: x as i64, y: y as i64 };
+                if on_loop.contains(&p) {
+                    let mut c = tile;
+                    if tile == 'S' {
+                        // close the loop
+                        c = if Direction::Left.is_valid_from(c) && Direction::Right.is_valid_from(c) { '-' }
+                        else if Direction::Up.is_valid_from(c) && Direction::Down.is_valid_from(c) { '|' }
+                        else if Direction::Left.is_valid_from(c) && Direction::Up.is_valid_from(c) { 'J' }
+                        else if Direction::Left.is_valid_from(c) && Direction::Down.is_valid_from(c) { '7' }
+                        else if Direction::Right.is_valid_from(c) && Direction::Up.is_valid_from(c) { 'L' }
+                        else if Direction::Right.is_valid_from(c) && Direction::Down.is_valid_from(c) { 'F' }
+                        else { '.' }
+                    }
+                    if c == '|' || c == 'L' || c == 'J' {
+                        is_inside = is_inside.not();
+                    }
+                } else  {
+                    if is_inside {
+                        enclosed_tiles.insert(p);
+                    }
+                }
+            }
+        }
+
+        Ok(PuzzleResult::from(enclosed_tiles.len()))
+    }
+
+    /// A `size` by `size` field of junk pipes with a loop through the start tile. The loop runs around a random
+    /// spanning tree of blocks of 2 by 2 tiles, which visits every tile of the blocks once, and is then drawn at twice
+    /// the scale so that it encloses the tiles between its turns.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(3);
+        let blocks = ((size + 1) / 4).max(1);
+        let tiles = 2 * blocks;
+        // right[y][x] links tile (x, y) with (x + 1, y) and down[y][x] links it with (x, y + 1)
+        let mut right = vec![vec![false; tiles]; tiles];
+        let mut down = vec![vec![false; tiles]; tiles];
+        for (x, y) in (0..blocks).cartesian_product(0..blocks).map(|(x, y)| (2 * x, 2 * y)) {
+            right[y][x] = true;
+            right[y + 1][x] = true;
+            down[y][x] = true;
+            down[y][x + 1] = true;
+        }
+        let mut visited = vec![vec![false; blocks]; blocks];
+        visited[0][0] = true;
+        let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+        while let Some(&(x, y)) = stack.last() {
+            let mut next = [(x + 1, y), (x, y + 1), (x.wrapping_sub(1), y), (x, y.wrapping_sub(1))]
+                .into_iter()
+                .filter(|&(nx, ny)| nx < blocks && ny < blocks && !visited[ny][nx])
+                .collect_vec();
+            if next.is_empty() {
+                stack.pop();
+                continue;
+            }
+            rng.shuffle(&mut next);
+            let (nx, ny) = next[0];
+            visited[ny][nx] = true;
+            stack.push((nx, ny));
+            // merge the loops around both blocks into one
+            let horizontal = ny == y;
+            let (x, y) = (2 * x.min(nx), 2 * y.min(ny));
+            if horizontal {
+                down[y][x + 1] = false;
+                down[y][x + 2] = false;
+                right[y][x + 1] = true;
+                right[y + 1][x + 1] = true;
+            } else {
+                right[y + 1][x] = false;
+                right[y + 2][x] = false;
+                down[y + 1][x] = true;
+                down[y + 1][x + 1] = true;
+            }
+        }
+        let pipes = "|-LJ7F..".chars().collect_vec();
+        let mut map = (0..size).map(|_| (0..size).map(|_| *rng.pick(&pipes)).collect_vec()).collect_vec();
+        let mut on_loop = vec![vec![false; size]; size];
+        let (left, top) = (rng.range(0..=size + 1 - 4 * blocks), rng.range(0..=size + 1 - 4 * blocks));
+        for (x, y) in (0..tiles).cartesian_product(0..tiles) {
+            let links = (y > 0 && down[y - 1][x], down[y][x], x > 0 && right[y][x - 1], right[y][x]);
+            let (mx, my) = (left + 2 * x, top + 2 * y);
+            map[my][mx] = match links {
+                (true, true, _, _) => '|',
+                (_, _, true, true) => '-',
+                (true, _, _, true) => 'L',
+                (true, _, true, _) => 'J',
+                (_, true, true, _) => '7',
+                _ => 'F',
+            };
+            on_loop[my][mx] = true;
+            if right[y][x] {
+                map[my][mx + 1] = '-';
+                on_loop[my][mx + 1] = true;
+            }
+            if down[y][x] {
+                map[my + 1][mx] = '|';
+                on_loop[my + 1][mx] = true;
+            }
+        }
+        // a junk pipe next to the start would lead the search off the loop
+        let start = *rng.pick(&(0..size).cartesian_product(0..size).filter(|&(x, y)| on_loop[y][x]).collect_vec());
+        map[start.1][start.0] = 'S';
+        for (dx, dy, _) in DIRECTIONS {
+            let (x, y) = ((start.0 as i64 + dx) as usize, (start.1 as i64 + dy) as usize);
+            if x < size && y < size && !on_loop[y][x] {
+                map[y][x] = '.';
+            }
+        }
+        Some(text(&map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day10::Day10;
+    use crate::error::AocError;
+
+    const INPUT1: &str = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+
+    const INPUT2: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    #[test]
+    fn part1() { assert_eq!(Day10.part1(INPUT1), Ok(PuzzleResult::Number(8))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day10.part2(INPUT2), Ok(PuzzleResult::Number(4))); }
+
+    #[test]
+    fn no_loop() {
+        assert_eq!(Day10.part1("..F7.\n.FJ|.\n.J.L7"), Err(AocError::parse(10, 1, "missing the start tile `S`")));
+        assert_eq!(Day10.part1("S-7\n...\n..."), Err(AocError::unsolvable(10, "the start tile is not on a loop")));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day10.part1("S-7\n|.|\nL-x"), Err(AocError::parse_at(10, 3, 3, "expected a pipe, `.` or `S`, found `x`")));
+        assert_eq!(Day10.part1("S-7\n|.S\nL-J"), Err(AocError::parse_at(10, 2, 3, "a second start tile `S`")));
+    }
+}
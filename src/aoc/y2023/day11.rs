@@ -0,0 +1,98 @@
+use itertools::Itertools;
+use crate::aoc::util::{CharMap, Point};
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::{text, Rng};
+use crate::aoc::input::{grid, lines};
+use crate::error::AocError;
+
+impl Point {
+    pub fn new_with_expansion(&self, empty_rows: &[i64], empty_cols: &[i64], factor: i64) -> Self {
+        let mut new = *self;
+        (0..self.x)
+            .filter(|x| empty_cols.contains(x))
+            .for_each(|_| new.x += factor);
+        (0..self.y)
+            .filter(|y| empty_rows.contains(y))
+            .for_each(|_| new.y += factor);
+        new
+    }
+}
+
+fn sum_of_distances(map: &CharMap, factor: i64) -> i64 {
+    let empty_rows = map.filter_rows(|&x| x == '.');
+    let empty_cols = map.filter_cols(|&x| x == '.');
+    let galaxies = map.find_all(|&x| x == '#')
+        .iter()
+        .map(|x| x.new_with_expansion(&empty_rows, &empty_cols, factor))
+        .collect_vec();
+    (0..galaxies.len()).fold(0, |acc1, i| {
+        acc1 + (i+1..galaxies.len()).fold(0, |acc2, j| {
+            acc2 + galaxies[i].manhattan(&galaxies[j])
+        })
+    })
+}
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    type Input = CharMap;
+
+    const DAY: u8 = 11;
+
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        grid(Self::DAY, &lines(input).collect_vec(), "`.` or `#`", |c| c == '.' || c == '#')?;
+        CharMap::parse(input).ok_or_else(|| AocError::parse(Self::DAY, 1, "expected a non-empty map without blank lines"))
+    }
+
+    fn part1(map: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::Number(sum_of_distances(map, 1)))
+    }
+
+    fn part2(map: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::Number(sum_of_distances(map, 999999)))
+    }
+
+    /// A `size` by `size` image with a galaxy on about one of 20 tiles and about one of ten rows and columns empty.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let empty_rows = (0..size).map(|_| rng.chance(0.1)).collect_vec();
+        let empty_cols = (0..size).map(|_| rng.chance(0.1)).collect_vec();
+        let rows = empty_rows.iter()
+            .map(|&empty_row| empty_cols.iter()
+                .map(|&empty_col| if !empty_row && !empty_col && rng.chance(0.05) { '#' } else { '.' })
+                .collect_vec())
+            .collect_vec();
+        Some(text(&rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day11::Day11;
+    use crate::error::AocError;
+
+    const INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn part1() { assert_eq!(Day11.part1(INPUT), Ok(PuzzleResult::Number(374))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day11.part2(INPUT), Ok(PuzzleResult::Number(82000210))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day11.part1("...#\n#..\n"), Err(AocError::parse_at(11, 2, 4, "expected `.` or `#`, found the end of the line")));
+        assert_eq!(Day11.part1("...#\n\n#...\n"), Err(AocError::parse_at(11, 2, 1, "expected `.` or `#`, found the end of the line")));
+    }
+}
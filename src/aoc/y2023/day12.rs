@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use itertools::Itertools;
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+const DAMAGED: char = '#';
+const OPERATIONAL: char = '.';
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+struct Position {
+    s: usize,
+    n: usize,
+}
+
+impl Position {
+    pub fn new() -> Self { Position { s: 0, n: 0 } }
+}
+
+type CountCache = HashMap<Position, usize>;
+
+pub struct ConditionRecord {
+    springs: Vec<char>,
+    numbers: Vec<usize>,
+}
+
+impl ConditionRecord {
+    fn number(&self, pos: &Position) -> usize { self.numbers[pos.n] }
+
+    fn spring(&self, pos: &Position) -> char { self.springs[pos.s] }
+
+    fn find_first(&self, pos: &Position, pred: fn(char) -> bool) -> Option<Position> {
+        let mut pos = *pos;
+        while pos.s < self.springs.len() {
+            if pred(self.spring(&pos)) { return Some(pos); }
+            pos.s += 1;
+        }
+        None
+    }
+
+    /// The record five times over, the copies of the springs separated by an unknown one.
+    fn unfold(&self) -> Self {
+        ConditionRecord { springs: vec![self.springs.clone(); 5].join(&'?'), numbers: self.numbers.repeat(5) }
+    }
+}
+
+type ConditionRecords = Vec<ConditionRecord>;
+
+fn count_arrangements(rec: &ConditionRecord, pos: Position, cache: &mut CountCache) -> usize {
+    if rec.numbers.len() == pos.n {
+        if (pos.s..rec.springs.len()).any(|i| rec.springs[i] == DAMAGED) {
+            return 0;
+        }
+        return 1;
+    }
+    let Some(pos) = rec.find_first(&pos, |c| c != OPERATIONAL) else {
+        return 0;
+    };
+    if pos.s + rec.number(&pos) > rec.springs.len() {
+        return 0;
+    }
+    if cache.contains_key(&pos) {
+        return *cache.get(&pos).unwrap_or(&0);
+    };
+    let mut count = 0;
+    if (pos.s..pos.s + rec.number(&pos)).all(|i| rec.springs[i] != OPERATIONAL)
+        && (pos.s + rec.number(&pos) == rec.springs.len() || rec.springs[pos.s + rec.number(&pos)] != DAMAGED) {
+        count += count_arrangements(rec, Position { s: pos.s + rec.number(&pos) + 1, n: pos.n + 1 }, cache);
+    }
+    if rec.spring(&pos) != DAMAGED {
+        count += count_arrangements(rec, Position { s: pos.s + 1, n: pos.n }, cache);
+    }
+    cache.insert(pos, count);
+    count
+}
+
+/// A `SPRINGS SIZE,SIZE,...` line per record.
+fn load_input(input: &str) -> Result<ConditionRecords, AocError> {
+    lines(input).map(|(line_nr, line)| {
+        let mut scanner = Scanner::new(Day12::DAY, line_nr, line);
+        let springs = scanner.token("`.`, `#` or `?`", |c| matches!(c, '.' | '#' | '?'))?.chars().collect_vec();
+        scanner.expect(" ")?;
+        let mut numbers = vec![];
+        loop {
+            let column = scanner.column();
+            let number = scanner.number::<usize>()?;
+            if number == 0 {
+                return Err(scanner.error_at(column, "a group has at least one damaged spring"));
+            }
+            numbers.push(number);
+            if !scanner.eat(",") {
+                break;
+            }
+        }
+        scanner.end()?;
+        Ok(ConditionRecord { springs, numbers })
+    }).collect()
+}
+
+pub struct Day12;
+
+impl Puzzle for Day12 {
+    type Input = ConditionRecords;
+
+    const DAY: u8 = 12;
+
+    const TITLE: &'static str = "Hot Springs";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> { load_input(input) }
+
+    fn part1(records: &Self::Input) -> Result<PuzzleResult, AocError> {
+        let mut cache = CountCache::new();
+        Ok(PuzzleResult::from(records
+            .iter()
+            .map(|record| {
+                cache.clear();
+                count_arrangements(record, Position::new(), &mut cache)
+            })
+            .sum::<usize>()))
+    }
+
+    fn part2(records: &Self::Input) -> Result<PuzzleResult, AocError> {
+        let mut cache = CountCache::new();
+        Ok(PuzzleResult::from(records
+            .iter()
+            .map(|record| {
+                cache.clear();
+                count_arrangements(&record.unfold(), Position::new(), &mut cache)
+            })
+            .sum::<usize>()))
+    }
+
+    /// `size` records of up to 20 springs with at most eleven unknown ones, which keeps the count of a record below
+    /// 2^64 even unfolded. Records with more than a trillion arrangements unfolded are drawn again.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut cache = CountCache::new();
+        let mut input = String::new();
+        for _ in 0..size {
+            let record = loop {
+                let mut springs = (0..rng.range(1..=20)).map(|_| if rng.chance(0.4) { DAMAGED } else { OPERATIONAL }).collect_vec();
+                let numbers = springs.split(|&c| c == OPERATIONAL).map(<[char]>::len).filter(|&len| len > 0).collect_vec();
+                if numbers.is_empty() {
+                    continue;
+                }
+                let mut unknown = (0..springs.len()).collect_vec();
+                rng.shuffle(&mut unknown);
+                for &i in &unknown[..rng.range(0..=springs.len().min(11))] {
+                    springs[i] = '?';
+                }
+                let record = ConditionRecord { springs, numbers };
+                cache.clear();
+                if count_arrangements(&record.unfold(), Position::new(), &mut cache) <= 1_000_000_000_000 {
+                    break record;
+                }
+            };
+            input += &format!("{} {}\n", record.springs.iter().collect::<String>(), record.numbers.iter().join(","));
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day12::Day12;
+    use crate::error::AocError;
+
+    const INPUT: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    #[test]
+    fn part1() { assert_eq!(Day12.part1(INPUT), Ok(PuzzleResult::Number(21))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day12.part2(INPUT), Ok(PuzzleResult::Number(525152))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day12.part1("???.### 1,,3"), Err(AocError::parse_at(12, 1, 11, "expected a number, found `,`")));
+        assert_eq!(Day12.part1("???.### 1,0,3"), Err(AocError::parse_at(12, 1, 11, "a group has at least one damaged spring")));
+        assert_eq!(Day12.part1("???.### 1,1,3\n???.###"), Err(AocError::parse_at(12, 2, 8, "expected ` `, found the end of the line")));
+        assert_eq!(Day12.part1("??x.### 1,1,3"), Err(AocError::parse_at(12, 1, 3, "expected ` `, found `x`")));
+    }
+}
\ No newline at end of file
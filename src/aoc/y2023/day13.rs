@@ -0,0 +1,155 @@
+use crate::aoc::util::{CharMap, count_diff};
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::{text, Rng};
+use crate::aoc::input::{grid, lines};
+use crate::error::AocError;
+
+/// Every mirror of a pattern, first between the columns and then between the rows, with its note and the number of
+/// tiles that differ from their reflection.
+fn mirrors(map: &CharMap) -> Vec<(usize, usize)> {
+    let mut mirrors = vec![];
+    for i in 0..map.width() - 1 {
+        let mut diffs = 0;
+        let mut c0 = i as i64;
+        let mut c1 = i + 1;
+        while c0 >= 0 && c1 < map.width() {
+            diffs += count_diff(&map.col_at(c0 as usize), &map.col_at(c1));
+            c0 -= 1;
+            c1 += 1;
+        }
+        mirrors.push((i + 1, diffs));
+    }
+    for i in 0..map.height() - 1 {
+        let mut diffs = 0;
+        let mut r0 = i as i64;
+        let mut r1 = i + 1;
+        while r0 >= 0 && r1 < map.height() {
+            diffs += count_diff(&map[r0 as usize], &map[r1]);
+            r0 -= 1;
+            r1 += 1;
+        }
+        mirrors.push((100 * (i + 1), diffs));
+    }
+    mirrors
+}
+
+/// The sum of the notes of the first mirror in each pattern whose differences match `pred`.
+fn summarize_pattern_notes(maps: &[CharMap], pred: fn(usize) -> bool) -> Result<usize, AocError> {
+    maps.iter().enumerate().try_fold(0, |sum, (n, map)| {
+        let (note, _) = mirrors(map).into_iter().find(|&(_, diffs)| pred(diffs))
+            .ok_or_else(|| AocError::unsolvable(Day13::DAY, format!("pattern {} has no mirror", n + 1)))?;
+        Ok(sum + note)
+    })
+}
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    type Input = Vec<CharMap>;
+
+    const DAY: u8 = 13;
+
+    const TITLE: &'static str = "Point of Incidence";
+
+    /// Patterns separated by an empty line.
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let lines = lines(input).collect::<Vec<(usize, &str)>>();
+        let mut maps = vec![];
+        for pattern in lines.split(|(_, line)| line.is_empty()) {
+            let Some(&(line_nr, _)) = pattern.first() else {
+                let line_nr = lines.iter().zip(&lines[1..]).find(|(a, b)| a.1.is_empty() && b.1.is_empty()).map_or(1, |(_, b)| b.0);
+                return Err(AocError::parse_at(Self::DAY, line_nr, 1, "expected a pattern, found another empty line"));
+            };
+            grid(Self::DAY, pattern, "`.` or `#`", |c| c == '.' || c == '#')?;
+            let text = pattern.iter().map(|(_, line)| *line).collect::<Vec<&str>>().join("\n");
+            maps.push(CharMap::parse(&text).ok_or_else(|| AocError::parse(Self::DAY, line_nr, "expected a pattern"))?);
+        }
+        Ok(maps)
+    }
+
+    fn part1(maps: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(summarize_pattern_notes(maps, |diff| diff == 0)?))
+    }
+
+    fn part2(maps: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(summarize_pattern_notes(maps, |diff| diff == 1)?))
+    }
+
+    /// `size` patterns of 5 to 17 rows and columns. Each has a mirror between two columns and one off by a smudge
+    /// between two rows, or the other way round, and no other mirror that is clean or off by one tile.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut patterns = vec![];
+        while patterns.len() < size {
+            let (width, height) = (rng.range(5..=17), rng.range(5..=17));
+            let (column, row) = (rng.range(1..=width - 1), rng.range(1..=height - 1));
+            if column * 2 == width {
+                continue; // every column is reflected, which leaves no column for the smudge
+            }
+            let mut rows = (0..height).map(|_| (0..width).map(|_| *rng.pick(&['.', '#'])).collect::<Vec<char>>()).collect::<Vec<_>>();
+            let reflected_rows = row.min(height - row);
+            for i in 0..reflected_rows {
+                rows[row + i] = rows[row - 1 - i].clone();
+            }
+            for tiles in rows.iter_mut() {
+                for i in 0..column.min(width - column) {
+                    tiles[column + i] = tiles[column - 1 - i];
+                }
+            }
+            // the smudge is on a reflected row but not on a reflected column
+            let x = if column * 2 < width { rng.range(2 * column..=width - 1) } else { rng.range(0..=2 * column - width - 1) };
+            let y = rng.range(row - reflected_rows..=row + reflected_rows - 1);
+            rows[y][x] = if rows[y][x] == '#' { '.' } else { '#' };
+            if rng.chance(0.5) {
+                rows = (0..width).map(|x| rows.iter().map(|tiles| tiles[x]).collect()).collect();
+            }
+            let pattern = text(&rows);
+            let mirrors = CharMap::parse(&pattern).map(|map| mirrors(&map)).unwrap_or_default();
+            if [0, 1].iter().all(|&n| mirrors.iter().filter(|&&(_, diffs)| diffs == n).count() == 1) {
+                patterns.push(pattern);
+            }
+        }
+        Some(patterns.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day13::Day13;
+    use crate::error::AocError;
+
+    const INPUT: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn part1() { assert_eq!(Day13.part1(INPUT), Ok(PuzzleResult::Number(405))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day13.part2(INPUT), Ok(PuzzleResult::Number(400))); }
+
+    #[test]
+    fn no_mirror() {
+        let input = "#.#\n#.#\n\n#..\n..#\n.#.\n";
+        assert_eq!(Day13.part1(input), Err(AocError::unsolvable(13, "pattern 2 has no mirror")));
+        assert_eq!(Day13.part2(input), Err(AocError::unsolvable(13, "pattern 1 has no mirror")));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day13.part1("#.#\n..#\n\n#.\n.#.\n"), Err(AocError::parse_at(13, 5, 3, "expected the end of the line, found `.`")));
+        assert_eq!(Day13.part1("#.#\n..#\n\n\n#.\n"), Err(AocError::parse_at(13, 4, 1, "expected a pattern, found another empty line")));
+    }
+}
\ No newline at end of file
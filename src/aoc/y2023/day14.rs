@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use crate::aoc::util::CharMap;
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::{text, Rng};
+use crate::aoc::input::{grid, lines};
+use crate::error::AocError;
+
+fn tilt_north(map: &mut CharMap) {
+    for col in 0..map.width() {
+        let mut empty_row: Option<usize> = None;
+        for mut row in 0..map.height() {
+            if map[row][col] == 'O' && empty_row.is_some() {
+                map[row][col] = '.';
+                map[empty_row.unwrap()][col] = 'O';
+                row = empty_row.unwrap() + 1; // start again from +1 relative to last empty
+                empty_row = None;
+            }
+            if map[row][col] == '#' && empty_row.is_some() {
+                empty_row = None;
+            }
+            if map[row][col] == '.' && empty_row.is_none() {
+                empty_row = Some(row);
+            }
+        }
+    }
+}
+
+fn cycle(map: &mut CharMap) {
+    tilt_north(map); // N
+    map.rotate_clockwise();
+    tilt_north(map); // W
+    map.rotate_clockwise();
+    tilt_north(map); // E
+    map.rotate_clockwise();
+    tilt_north(map); // S
+    map.rotate_clockwise();
+}
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    type Input = CharMap;
+
+    const DAY: u8 = 14;
+
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        grid(Self::DAY, &lines(input).collect::<Vec<(usize, &str)>>(), "`O`, `#` or `.`", |c| matches!(c, 'O' | '#' | '.'))?;
+        CharMap::parse(input).ok_or_else(|| AocError::parse(Self::DAY, 1, "expected a non-empty map without blank lines"))
+    }
+
+    fn part1(map: &Self::Input) -> Result<PuzzleResult, AocError> {
+        let mut map = map.clone();
+        tilt_north(&mut map);
+        Ok(PuzzleResult::Number(map.find_all(|&x| x == 'O').iter().map(|p| map.height() as i64 - p.y).sum()))
+    }
+
+    fn part2(map: &Self::Input) -> Result<PuzzleResult, AocError> {
+        let mut cache: HashMap<CharMap, usize> = HashMap::new();
+        let mut map = map.clone();
+        for i in 0..1_000_000_000 {
+            cycle(&mut map);
+            if let Some(nth_cycle) = cache.get(&map) { // detected cycle
+                let cycle_len = i - nth_cycle;
+                let spins_left = (1_000_000_000 - 1 - nth_cycle) % cycle_len;
+                for _ in 0..spins_left {
+                    cycle(&mut map); // compute the remaining cycles excluding the periods
+                }
+                break;
+            }
+            cache.insert(map.clone(), i);
+        }
+        Ok(PuzzleResult::Number(map.find_all(|&x| x == 'O').iter().map(|p| map.height() as i64 - p.y).sum()))
+    }
+
+    /// A `size` by `size` platform with a rounded rock on about one of five tiles and a cube rock on one of ten.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rows = (0..size)
+            .map(|_| (0..size).map(|_| match rng.below(10) { 0 | 1 => 'O', 2 => '#', _ => '.' }).collect())
+            .collect::<Vec<Vec<char>>>();
+        Some(text(&rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day14::Day14;
+    use crate::error::AocError;
+
+    const INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn part1() { assert_eq!(Day14.part1(INPUT), Ok(PuzzleResult::Number(136))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day14.part2(INPUT), Ok(PuzzleResult::Number(64))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day14.part1("O..#\n.0.#"), Err(AocError::parse_at(14, 2, 2, "expected `O`, `#` or `.`, found `0`")));
+    }
+}
\ No newline at end of file
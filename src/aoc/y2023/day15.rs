@@ -0,0 +1,127 @@
+use itertools::Itertools;
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::aoc::generate::Rng;
+use crate::aoc::input::{lines, Scanner};
+use crate::error::AocError;
+
+fn hash(data: &str) -> usize {
+    data.chars().fold(0, |mut acc, c|{
+        acc += c as usize;
+        acc *= 17;
+        acc %= 256;
+        acc
+    })
+}
+
+/// A step of the initialization sequence, `LABEL=FOCAL_LENGTH` or `LABEL-`.
+pub struct Step {
+    text: String,
+    label: String,
+    focal_length: Option<u64>,
+}
+
+impl Step {
+    pub fn new(scanner: &mut Scanner) -> Result<Self, AocError> {
+        let start = scanner.rest();
+        let label = scanner.token("a label", |c| c.is_ascii_lowercase())?.to_string();
+        let focal_length = match scanner.one_of(&["=", "-"])? {
+            "=" => Some(scanner.char("a focal length", |c| matches!(c, '1'..='9'))?.to_digit(10).unwrap_or_default().into()),
+            _ => None,
+        };
+        let text = start[..start.len() - scanner.rest().len()].to_string();
+        Ok(Step { text, label, focal_length })
+    }
+}
+
+pub struct Day15;
+
+impl Puzzle for Day15 {
+    type Input = Vec<Step>;
+
+    const DAY: u8 = 15;
+
+    const TITLE: &'static str = "Lens Library";
+
+    /// Steps separated by commas on a single line.
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut lines = lines(input);
+        let (_, line) = lines.next().unwrap_or((1, ""));
+        let mut scanner = Scanner::new(Self::DAY, 1, line);
+        let mut steps = vec![Step::new(&mut scanner)?];
+        while scanner.eat(",") {
+            steps.push(Step::new(&mut scanner)?);
+        }
+        scanner.end()?;
+        if let Some((line_nr, _)) = lines.find(|(_, line)| !line.is_empty()) {
+            return Err(AocError::parse(Self::DAY, line_nr, "expected the steps on a single line"));
+        }
+        Ok(steps)
+    }
+
+    fn part1(steps: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Ok(PuzzleResult::from(steps.iter().map(|x| hash(&x.text)).sum::<usize>()))
+    }
+
+    fn part2(steps: &Self::Input) -> Result<PuzzleResult, AocError> {
+        let operations = steps.iter().map(|step| (step.label.as_str(), step.focal_length)).collect_vec();
+        let mut boxes: Vec<Vec<(String, u64)>> = (0..256).map(|_| vec![]).collect();
+        for operation in operations {
+            match operation {
+                (label, Some(focal_length)) => { // (=) operation
+                    if let Some((pos, _)) = boxes[hash(label)].iter().find_position(|(l, _)| l == label) {
+                        boxes[hash(label)][pos] = (label.into(), focal_length);
+                    } else {
+                        boxes[hash(label)].push((label.into(), focal_length));
+                    }
+                },
+                (label, None) => { // (-) operation
+                    if let Some((pos, _)) = boxes[hash(label)].iter().find_position(|(l, _)| l == label) {
+                        boxes[hash(label)].remove(pos);
+                    }
+                },
+            }
+        }
+        Ok(PuzzleResult::from(boxes.iter().enumerate().flat_map(|(box_nr, bx)|
+            bx.iter().enumerate().map(|(slot_nr, (_, fcl_l))| {
+                    (box_nr + 1) * (slot_nr + 1) * *fcl_l as usize
+            }).collect_vec()
+        ).sum::<usize>()))
+    }
+
+    /// `size` steps on lenses with labels of two to six letters, about four steps per lens.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let labels = (0..size.div_ceil(4))
+            .map(|_| (0..rng.range(2..=6)).map(|_| char::from(b'a' + rng.below(26) as u8)).collect::<String>())
+            .collect_vec();
+        let steps = (0..size)
+            .map(|_| match rng.pick(&labels) {
+                label if rng.chance(0.6) => format!("{label}={}", rng.range(1..=9)),
+                label => format!("{label}-"),
+            })
+            .collect_vec();
+        Some(steps.join(",") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y2023::day15::Day15;
+    use crate::error::AocError;
+
+    const INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn part1() { assert_eq!(Day15.part1(INPUT), Ok(PuzzleResult::Number(1320))); }
+
+    #[test]
+    fn part2() { assert_eq!(Day15.part2(INPUT), Ok(PuzzleResult::Number(145))); }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Day15.part1("rn=1,cm-,qp=0"), Err(AocError::parse_at(15, 1, 13, "expected a focal length, found `0`")));
+        assert_eq!(Day15.part1("rn=1,,cm-"), Err(AocError::parse_at(15, 1, 6, "expected a label, found `,`")));
+        assert_eq!(Day15.part1("rn=1,cm"), Err(AocError::parse_at(15, 1, 8, "expected `=` or `-`, found the end of the line")));
+        assert_eq!(Day15.part1("rn=1,cm-\nqp=3"), Err(AocError::parse(15, 2, "expected the steps on a single line")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use crate::aoc::common::Solver;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+/// Every implemented day of 2023, in order. A new day only needs to be added here to be picked up by the runner.
+pub static SOLVERS: &[&dyn Solver] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::y2023::SOLVERS;
+
+    #[test]
+    fn registry_is_ordered_by_day() {
+        assert!(SOLVERS.windows(2).all(|w| w[0].day() < w[1].day()));
+    }
+}
@@ -0,0 +1,111 @@
+use std::time::Duration;
+use crate::aoc::common::{Part, Solver};
+use crate::error::AocError;
+
+/// Summary of repeated measurements of a single step.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        if samples.is_empty() { return Stats::default(); }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let secs = samples.iter().map(Duration::as_secs_f64).collect::<Vec<f64>>();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = secs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / secs.len() as f64;
+        let median = (sorted[(sorted.len() - 1) / 2] + sorted[sorted.len() / 2]) / 2;
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+/// Runs a day `iterations` times after a short warm-up and measures parsing and the selected parts separately.
+pub fn bench(solver: &dyn Solver, input: &str, iterations: usize, part: Part) -> Result<DayBench, AocError> {
+    for _ in 0..(iterations / 10).max(1) {
+        solver.run(input, part, None)?;
+    }
+    let (mut parse, mut part1, mut part2) = (vec![], vec![], vec![]);
+    for _ in 0..iterations {
+        let run = solver.run(input, part, None)?;
+        parse.push(run.parse);
+        part1.extend(run.part1.transpose()?.map(|(_, runtime)| runtime));
+        part2.extend(run.part2.transpose()?.map(|(_, runtime)| runtime));
+    }
+    Ok(DayBench {
+        day: solver.day(),
+        parse: Stats::new(&parse),
+        part1: part.includes(1).then(|| Stats::new(&part1)),
+        part2: part.includes(2).then(|| Stats::new(&part2)),
+    })
+}
+
+pub fn print(benches: &[DayBench]) {
+    println!("{:<4} {:<7} {:>12} {:>12} {:>12} {:>12}", "Day", "Step", "min", "median", "mean", "stddev");
+    for bench in benches {
+        let steps = [("parse", Some(&bench.parse)), ("part 1", bench.part1.as_ref()), ("part 2", bench.part2.as_ref())];
+        for (step, stats) in steps.into_iter().filter_map(|(step, stats)| Some((step, stats?))) {
+            println!(
+                "{:<4} {:<7} {:>12} {:>12} {:>12} {:>12}",
+                format!("{:02}", bench.day), step,
+                format!("{:.2?}", stats.min), format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean), format!("{:.2?}", stats.stddev)
+            );
+        }
+    }
+    let total = |f: fn(&DayBench) -> Duration| benches.iter().map(f).sum::<Duration>();
+    let (parse, part1, part2) = (
+        total(|b| b.parse.mean),
+        total(|b| b.part1.unwrap_or_default().mean),
+        total(|b| b.part2.unwrap_or_default().mean),
+    );
+    println!(
+        "Total (mean): parse {:.2?}, part 1 {:.2?}, part 2 {:.2?}, overall {:.2?}",
+        parse, part1, part2, parse + part1 + part2
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::aoc::common::Part;
+    use crate::aoc::y2023::day09::Day09;
+    use crate::bench::{bench, Stats};
+
+    #[test]
+    fn stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::new(&samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+        assert_eq!(Stats::new(&samples[..3]).median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn bench_day() {
+        let result = bench(&Day09, "0 3 6 9 12 15\n1 3 6 10 15 21", 5, Part::Both).unwrap();
+        assert_eq!(result.day, 9);
+        let part1 = result.part1.unwrap();
+        assert!(part1.min <= part1.median && part1.median <= part1.mean * 2);
+        assert!(result.part2.is_some());
+        assert!(bench(&Day09, "0 3 6 9 12 15", 5, Part::Two).unwrap().part1.is_none());
+    }
+}
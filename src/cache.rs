@@ -0,0 +1,108 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use crate::error::AocError;
+
+/// Puzzle inputs stored on disk as `{root}/{year}/day{day:02}.txt`, next to the puzzle pages as `day{day:02}.html` and
+/// the private leaderboards as `leaderboard-{id}.json`.
+pub struct InputCache {
+    root: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        InputCache { root: root.into() }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("day{day:02}.txt"))
+    }
+
+    pub fn puzzle_path(&self, year: u16, day: u8) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("day{day:02}.html"))
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<Option<String>, AocError> {
+        read(&self.path(year, day))
+    }
+
+    /// Writes to a temporary file next to the target and renames it, so an interrupted run never leaves a
+    /// truncated input behind.
+    pub fn store(&self, year: u16, day: u8, input: &str) -> Result<(), AocError> {
+        write(&self.path(year, day), input)
+    }
+
+    pub fn load_puzzle(&self, year: u16, day: u8) -> Result<Option<String>, AocError> {
+        read(&self.puzzle_path(year, day))
+    }
+
+    pub fn store_puzzle(&self, year: u16, day: u8, page: &str) -> Result<(), AocError> {
+        write(&self.puzzle_path(year, day), page)
+    }
+
+    pub fn leaderboard_path(&self, year: u16, id: u64) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("leaderboard-{id}.json"))
+    }
+
+    /// The cached leaderboard together with how long ago it was downloaded.
+    pub fn load_leaderboard(&self, year: u16, id: u64) -> Result<Option<(String, Duration)>, AocError> {
+        let path = self.leaderboard_path(year, id);
+        let Some(json) = read(&path)? else { return Ok(None) };
+        let age = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .map(|modified| SystemTime::now().duration_since(modified).unwrap_or_default())
+            .map_err(|err| AocError::Io(format!("failed to read cached file {}: {err}", path.display())))?;
+        Ok(Some((json, age)))
+    }
+
+    pub fn store_leaderboard(&self, year: u16, id: u64, json: &str) -> Result<(), AocError> {
+        write(&self.leaderboard_path(year, id), json)
+    }
+}
+
+fn read(path: &Path) -> Result<Option<String>, AocError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(AocError::Io(format!("failed to read cached file {}: {err}", path.display()))),
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), AocError> {
+    write_atomic(path, contents).map_err(|err| AocError::Io(format!("failed to cache {}: {err}", path.display())))
+}
+
+pub(crate) fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".tmp{}", std::process::id()));
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path).inspect_err(|_| { let _ = fs::remove_file(&tmp); })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use crate::cache::InputCache;
+
+    #[test]
+    fn path() {
+        let cache = InputCache::new("inputs");
+        assert_eq!(cache.path(2023, 5), Path::new("inputs/2023/day05.txt"));
+    }
+
+    #[test]
+    fn store_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        assert_eq!(cache.load(2023, 1), Ok(None));
+        cache.store(2023, 1, "1abc2\n").unwrap();
+        assert_eq!(cache.load(2023, 1), Ok(Some(String::from("1abc2\n"))));
+        cache.store(2023, 1, "treb7uchet\n").unwrap();
+        assert_eq!(cache.load(2023, 1), Ok(Some(String::from("treb7uchet\n"))));
+        assert_eq!(std::fs::read_dir(dir.path().join("2023")).unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,655 @@
+use std::io::{IsTerminal, Read};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::OnceLock;
+use std::time::Duration;
+use clap::{Parser, Subcommand};
+use futures::{stream, StreamExt};
+use rayon::prelude::*;
+use itertools::Itertools;
+use crate::aoc;
+use crate::aoc::input;
+use crate::aoc::common::{Part, PartRun, Solver, SOLVER_STACK_SIZE};
+use crate::answers::AnswerStore;
+use crate::cache::InputCache;
+use crate::client::{AocClient, DEFAULT_BASE_URL, MAX_CONCURRENT_REQUESTS};
+use crate::error::AocError;
+use crate::examples::ExampleStore;
+use crate::history::{Entry, History, Tags};
+use crate::leaderboard::{Leaderboard, POLL_INTERVAL};
+use crate::report::{DayResult, Format, Status};
+use crate::submit::Verdict;
+use crate::token::{Session, TokenArgs};
+use crate::watch::Watcher;
+use crate::{bench, cache, history, leaderboard, puzzle, report, scaffold, server, watch};
+
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Year for which to solve the Advent of Code
+    #[arg(short, long, global = true, default_value_t = 2023)]
+    year: u16,
+    /// Day for which to solve the Advent of Code, 0 means solve all days
+    #[arg(short, long, global = true, default_value_t = 0)]
+    day: u8,
+    #[command(flatten)]
+    session: TokenArgs,
+    /// Directory in which the puzzle inputs are cached
+    #[arg(long, global = true, default_value = "inputs")]
+    cache_dir: PathBuf,
+    /// Download the puzzle input even if it is already cached
+    #[arg(long, global = true)]
+    refresh: bool,
+    /// Solve the given day using the puzzle input from this file instead, `-` reads from stdin
+    #[arg(short, long, global = true, conflicts_with = "refresh")]
+    input: Option<PathBuf>,
+    /// Address of the Advent of Code website
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+    /// Directory in which the accepted answers are stored
+    #[arg(long, global = true, default_value = "answers")]
+    answers_dir: PathBuf,
+    /// Directory in which the runtimes of every run are recorded
+    #[arg(long, global = true, default_value = "history")]
+    history_dir: PathBuf,
+    /// Report where the session token was taken from
+    #[arg(short, long, global = true)]
+    verbose: bool,
+    /// Compare the answers of every selected day against the accepted answers
+    #[arg(long)]
+    check: bool,
+    /// Measure parsing and both parts of every selected day over repeated runs
+    #[arg(long, conflicts_with = "check")]
+    bench: bool,
+    /// Solve the selected days again whenever their input files change, until interrupted
+    #[arg(long, conflicts_with_all = ["check", "bench", "refresh"])]
+    watch: bool,
+    /// Number of measured runs per day in benchmark mode
+    #[arg(long, requires = "bench", default_value_t = 100)]
+    iterations: usize,
+    /// Part of every selected day to solve
+    #[arg(short, long, value_enum, default_value_t = Part::Both)]
+    part: Part,
+    /// Seconds after which a part is abandoned and reported as timed out
+    #[arg(long, global = true, value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Number of threads used to solve the days, 0 means one per CPU
+    #[arg(long, default_value_t = 0)]
+    threads: usize,
+    /// Fail when a part is more than this many percent slower than the median of its last recorded runs
+    #[arg(long, value_name = "PCT")]
+    fail_on_regression: Option<f64>,
+    /// Output format of the results
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve one part of a day and submit the answer
+    Submit {
+        /// Part of the day to submit
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// List the implemented days of every year
+    List,
+    /// Check that the inputs of the selected days are well-formed without solving them
+    Validate,
+    /// Print a random well-formed input of a day, the same seed and size always give the same input
+    Generate {
+        /// Seed of the random input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Amount of data in the input, such as the number of lines or the width of a map
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Show the recorded runtimes of a day
+    History {
+        /// Number of most recent runs to show
+        #[arg(short, long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Show the description of a day
+    Read,
+    /// Extract the examples of the puzzle descriptions into test fixtures
+    Examples {
+        /// Directory of the fixtures
+        #[arg(long, default_value = "examples")]
+        dir: PathBuf,
+        /// Overwrite fixtures that already exist
+        #[arg(long)]
+        force: bool,
+    },
+    /// Create the module of a day from a template and register it
+    New {
+        /// Directory of the day modules [default: src/aoc/y{year}]
+        #[arg(long)]
+        module_dir: Option<PathBuf>,
+        /// Directory of the example fixtures, used for the tests of the module
+        #[arg(long, default_value = "examples")]
+        examples_dir: PathBuf,
+        /// Download the puzzle page and input first and extract the examples of the page
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Show a private leaderboard, with the star times of --day if given
+    Leaderboard {
+        /// Id of the private leaderboard
+        #[arg(long, required_unless_present = "file")]
+        id: Option<u64>,
+        /// Read the leaderboard JSON from this file instead of downloading it
+        #[arg(long, conflicts_with = "id")]
+        file: Option<PathBuf>,
+    },
+    /// Serve the solvers over HTTP, POST the input to /solve/{year}/{day}/{part} to get the answer as JSON
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value_t = IpAddr::V4(Ipv4Addr::LOCALHOST))]
+        host: IpAddr,
+        /// Port to listen on, 0 picks a free one
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+        /// Largest input accepted, in bytes
+        #[arg(long, default_value_t = server::MAX_BODY_BYTES)]
+        max_body: usize,
+        /// Most solves running at once, counting those still running after --timeout, further requests are refused
+        #[arg(long, default_value_t = server::MAX_SOLVES, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        max_solves: usize,
+    },
+}
+
+/// Runs the command line given to the process and returns its exit code.
+pub async fn main() -> ExitCode {
+    match run(Args::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+async fn run(args: Args) -> Result<(), AocError> {
+    if args.input.is_some() && args.day == 0 {
+        return Err(AocError::Usage(String::from("--input requires a single --day")));
+    }
+    match args.command {
+        None if args.check => check(&args).await,
+        None if args.bench => benchmark(&args).await,
+        None if args.watch => watch(&args).await,
+        None => solve(&args).await,
+        Some(Command::Submit { part }) => submit(&args, part).await,
+        Some(Command::List) => {
+            list();
+            Ok(())
+        }
+        Some(Command::Validate) => validate(&args).await,
+        Some(Command::Generate { seed, size }) => generate(&args, seed, size),
+        Some(Command::History { limit }) => history(&args, limit),
+        Some(Command::Read) => read(&args).await,
+        Some(Command::Examples { ref dir, force }) => {
+            let days = selected_solvers(&args)?.iter().map(|solver| solver.day()).collect::<Vec<u8>>();
+            extract_examples(&args, &days, dir, force).await
+        }
+        Some(Command::New { ref module_dir, ref examples_dir, fetch }) => {
+            let module_dir = module_dir.clone().unwrap_or_else(|| PathBuf::from(format!("src/aoc/y{}", args.year)));
+            new_day(&args, &module_dir, examples_dir, fetch).await
+        }
+        Some(Command::Leaderboard { id, ref file }) => show_leaderboard(&args, id, file.as_deref()).await,
+        Some(Command::Serve { host, port, max_body, max_solves }) => {
+            let limits = server::Limits { max_body, timeout: args.timeout, max_solves };
+            server::serve(SocketAddr::new(host, port), args.year, limits).await
+        }
+    }
+}
+
+fn selected_solvers(args: &Args) -> Result<Vec<&'static dyn Solver>, AocError> {
+    let year = args.year;
+    let solvers = aoc::solvers(year)
+        .ok_or_else(|| AocError::Usage(format!("no days of {year} are implemented, `list` shows which are")))?;
+    if args.day == 0 {
+        Ok(solvers.to_vec()) // solve all implemented days
+    } else {
+        Ok(vec![aoc::solver(year, args.day).ok_or_else(|| AocError::Usage(format!("day {} of {year} is not implemented", args.day)))?])
+    }
+}
+
+/// Solves every selected day, a day that fails is reported along with the others and the first failure is returned
+/// once all days are done.
+async fn solve(args: &Args) -> Result<(), AocError> {
+    let solvers = selected_solvers(args)?;
+    let inputs = load_inputs(args, &solvers).await;
+    let (results, errors): (Vec<DayResult>, Vec<Option<AocError>>) = solve_days(args, &solvers, inputs)?.into_iter().unzip();
+    print!("{}", report::render(&results, args.format)?);
+    let regressions = record_history(args, &results)?;
+    match errors.into_iter().flatten().next() {
+        Some(err) => Err(err),
+        None if regressions > 0 => Err(AocError::Regression(regressions)),
+        None => Ok(()),
+    }
+}
+
+/// Appends the runtimes to the history and returns how many parts regressed against it. Runs on an `--input` file are
+/// not recorded since their runtimes cannot be compared with those of the puzzle input.
+fn record_history(args: &Args, results: &[DayResult]) -> Result<usize, AocError> {
+    if args.input.is_some() {
+        return Ok(0);
+    }
+    let tags = Tags::current();
+    let mut history = History::load(&args.history_dir, args.year)?;
+    let regressions = args.fail_on_regression
+        .map(|percent| history.regressions(results, &tags.machine, percent))
+        .unwrap_or_default();
+    for regression in &regressions {
+        eprintln!("{regression}");
+    }
+    history.append(results.iter().filter_map(|result| Entry::new(&tags, result)).collect())?;
+    Ok(regressions.len())
+}
+
+fn list() {
+    println!("Year Day Title");
+    for year in aoc::YEARS {
+        for solver in year.solvers {
+            println!("{} {:>3} {}", year.year, format!("{:02}", solver.day()), solver.title());
+        }
+    }
+}
+
+/// Parses the input of every selected day and shows where a malformed input goes wrong, the first error is returned
+/// once all days are checked.
+async fn validate(args: &Args) -> Result<(), AocError> {
+    let solvers = selected_solvers(args)?;
+    let inputs = load_inputs(args, &solvers).await;
+    let mut errors = vec![];
+    for (solver, input) in solvers.iter().zip(inputs) {
+        let err = match input {
+            Ok(input) => match solver.validate(&input) {
+                Ok(()) => {
+                    println!("Day {:02}: ok", solver.day());
+                    continue;
+                }
+                Err(err) => {
+                    println!("Day {:02}: {err}", solver.day());
+                    if let Some(excerpt) = input::excerpt(&input, &err) {
+                        println!("{excerpt}");
+                    }
+                    err
+                }
+            },
+            Err(err) => {
+                println!("Day {:02}: {err}", solver.day());
+                err
+            }
+        };
+        errors.push(err);
+    }
+    errors.into_iter().next().map_or(Ok(()), Err)
+}
+
+fn generate(args: &Args, seed: u64, size: usize) -> Result<(), AocError> {
+    if args.day == 0 {
+        return Err(AocError::Usage(String::from("generate requires a single --day")));
+    }
+    let solver = selected_solvers(args)?[0];
+    let input = solver
+        .generate(seed, size)
+        .ok_or_else(|| AocError::Usage(format!("day {} of {} has no input generator", args.day, args.year)))?;
+    print!("{input}");
+    Ok(())
+}
+
+fn history(args: &Args, limit: usize) -> Result<(), AocError> {
+    if args.day == 0 {
+        return Err(AocError::Usage(String::from("history requires a single --day")));
+    }
+    history::print(&History::load(&args.history_dir, args.year)?, args.day, limit);
+    Ok(())
+}
+
+async fn read(args: &Args) -> Result<(), AocError> {
+    if args.day == 0 {
+        return Err(AocError::Usage(String::from("read requires a single --day")));
+    }
+    let page = load_puzzle(args, args.day).await?;
+    print!("{}", puzzle::render(&page, std::io::stdout().is_terminal())?);
+    Ok(())
+}
+
+/// Writes the examples of the given days, keeping existing fixtures unless `force` is set since they may have
+/// been corrected by hand.
+async fn extract_examples(args: &Args, days: &[u8], dir: &Path, force: bool) -> Result<(), AocError> {
+    let store = ExampleStore::new(dir);
+    for &day in days {
+        let examples = puzzle::examples(&load_puzzle(args, day).await?);
+        if examples.is_empty() {
+            println!("Day {day:02}: no example found");
+        }
+        let existing = store.load(args.year, day)?;
+        for example in examples {
+            let path = store.input_path(args.year, day, example.part);
+            if let (Some(kept), false) = (existing.iter().find(|kept| kept.part == example.part), force) {
+                match (&kept.answer, &example.answer) {
+                    (Some(kept), Some(answer)) if kept != answer => {
+                        println!("Day {day:02} part {}: kept {} expecting {kept}, the page gives {answer}", example.part, path.display())
+                    }
+                    _ => println!("Day {day:02} part {}: kept {}", example.part, path.display()),
+                }
+                continue;
+            }
+            store.store(args.year, day, &example)?;
+            match &example.answer {
+                Some(answer) => println!("Day {day:02} part {}: wrote {} expecting {answer}", example.part, path.display()),
+                None => println!("Day {day:02} part {}: wrote {} without an answer", example.part, path.display()),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes `dayNN.rs` from the template and registers it in `mod.rs`. With `fetch` the examples and the input are
+/// downloaded first, otherwise the cached page and existing fixtures are used if there are any.
+async fn new_day(args: &Args, module_dir: &Path, examples_dir: &Path, fetch: bool) -> Result<(), AocError> {
+    let day = args.day;
+    if !(1..=25).contains(&day) {
+        return Err(AocError::Usage(String::from("new requires a single --day between 1 and 25")));
+    }
+    let path = module_dir.join(format!("day{day:02}.rs"));
+    if path.exists() {
+        return Err(AocError::Usage(format!("{} already exists", path.display())));
+    }
+    let mod_rs = module_dir.join("mod.rs");
+    let registry = std::fs::read_to_string(&mod_rs)
+        .map_err(|err| AocError::Io(format!("failed to read {}: {err}", mod_rs.display())))?;
+    let registry = scaffold::register(&registry, day)?;
+    if fetch {
+        extract_examples(args, &[day], examples_dir, false).await?;
+        load_input(args, &InputCache::new(&args.cache_dir), &OnceLock::new(), day).await?;
+    }
+    let title = InputCache::new(&args.cache_dir)
+        .load_puzzle(args.year, day)?
+        .and_then(|page| puzzle::title(&page))
+        .unwrap_or_else(|| format!("Day {day}"));
+    let module = scaffold::module(args.year, day, &title, &ExampleStore::new(examples_dir).load(args.year, day)?);
+    for (path, contents) in [(&path, module), (&mod_rs, registry)] {
+        cache::write_atomic(path, &contents)
+            .map_err(|err| AocError::Io(format!("failed to write {}: {err}", path.display())))?;
+    }
+    println!("Created {} ({title}) and registered it in {}", path.display(), mod_rs.display());
+    Ok(())
+}
+
+/// The cached page unless it only describes part 1 while a session could unlock part 2, in which case it is fetched
+/// again and the cached page is the fallback if that fails.
+async fn load_puzzle(args: &Args, day: u8) -> Result<String, AocError> {
+    let cache = InputCache::new(&args.cache_dir);
+    let cached = if args.refresh { None } else { cache.load_puzzle(args.year, day)? };
+    let session = resolve_session(args)?;
+    let fetch = || async {
+        let page = AocClient::new(&args.base_url, session.as_ref().map(|x| x.token.as_str()))
+            .fetch_puzzle(args.year, day)
+            .await?;
+        cache.store_puzzle(args.year, day, &page)?;
+        Ok(page)
+    };
+    match cached {
+        Some(page) if session.is_some() && puzzle::descriptions(&page).len() < 2 => Ok(fetch().await.unwrap_or(page)),
+        Some(page) => Ok(page),
+        None => fetch().await,
+    }
+}
+
+async fn show_leaderboard(args: &Args, id: Option<u64>, file: Option<&Path>) -> Result<(), AocError> {
+    let json = match (id, file) {
+        (_, Some(path)) => std::fs::read_to_string(path)
+            .map_err(|err| AocError::Io(format!("failed to read the leaderboard {}: {err}", path.display())))?,
+        (Some(id), None) => load_leaderboard(args, id).await?,
+        (None, None) => return Err(AocError::Usage(String::from("leaderboard requires an --id or a --file"))),
+    };
+    print!("{}", leaderboard::render(&Leaderboard::parse(&json)?, (args.day != 0).then_some(args.day)));
+    Ok(())
+}
+
+/// The cached leaderboard while it is younger than `POLL_INTERVAL`, or without a session token to download it again.
+async fn load_leaderboard(args: &Args, id: u64) -> Result<String, AocError> {
+    let cache = InputCache::new(&args.cache_dir);
+    let cached = cache.load_leaderboard(args.year, id)?;
+    let session = resolve_session(args)?;
+    match (cached, session) {
+        (Some((json, age)), session) if age < POLL_INTERVAL || session.is_none() => {
+            if args.verbose {
+                eprintln!("Using the leaderboard downloaded {} minute(s) ago", age.as_secs() / 60);
+            }
+            Ok(json)
+        }
+        (_, None) => Err(AocError::Usage(String::from("downloading a leaderboard requires a --token"))),
+        (_, Some(session)) => {
+            let json = AocClient::new(&args.base_url, Some(&session.token)).fetch_leaderboard(args.year, id).await?;
+            cache.store_leaderboard(args.year, id, &json)?;
+            Ok(json)
+        }
+    }
+}
+
+async fn submit(args: &Args, part: u8) -> Result<(), AocError> {
+    if args.day == 0 {
+        return Err(AocError::Usage(String::from("submit requires a single --day")));
+    }
+    let solver = selected_solvers(args)?[0];
+    let Some(session) = resolve_session(args)? else {
+        return Err(AocError::Usage(String::from("submitting an answer requires a --token")));
+    };
+    let input_puzzle = load_input(args, &InputCache::new(&args.cache_dir), &OnceLock::new(), solver.day()).await?;
+    let answer = if part == 1 { solver.part1(&input_puzzle)? } else { solver.part2(&input_puzzle)? };
+    let verdict = AocClient::new(&args.base_url, Some(&session.token))
+        .submit_answer(args.year, solver.day(), part, &answer.to_string())
+        .await?;
+    println!("Day {} part {} answer {}: {}", solver.day(), part, answer, verdict);
+    if verdict == Verdict::Correct {
+        let mut answers = AnswerStore::load(&args.answers_dir, args.year)?;
+        answers.record(solver.day(), part, &answer);
+        answers.save()?;
+    }
+    Ok(())
+}
+
+async fn check(args: &Args) -> Result<(), AocError> {
+    let answers = AnswerStore::load(&args.answers_dir, args.year)?;
+    let solvers = selected_solvers(args)?;
+    let inputs = load_inputs(args, &solvers).await;
+    let mut mismatches = 0;
+    let mut first_error = None;
+    for (result, err) in solve_days(args, &solvers, inputs)? {
+        if let Some(err) = err {
+            println!("Day {:02}: ERROR ({})", result.day, result.error.as_deref().unwrap_or_default());
+            first_error.get_or_insert(err);
+        }
+        for (part, answer) in [(1, &result.part1), (2, &result.part2)] {
+            let Some(answer) = answer else { continue; };
+            let status = match answers.get(result.day, part) {
+                None => format!("MISSING ({answer})"),
+                Some(expected) if &expected == answer => format!("PASS ({answer})"),
+                Some(expected) => {
+                    mismatches += 1;
+                    format!("FAIL (got {answer}, expected {expected})")
+                }
+            };
+            println!("Day {:02} part {}: {}", result.day, part, status);
+        }
+    }
+    if let Some(err) = first_error {
+        return Err(err);
+    }
+    if mismatches > 0 {
+        return Err(AocError::AnswerMismatch(mismatches));
+    }
+    Ok(())
+}
+
+/// Solves the selected days, then again after every change of their input files, printing how the answers changed.
+async fn watch(args: &Args) -> Result<(), AocError> {
+    let cache = InputCache::new(&args.cache_dir);
+    let solvers = selected_solvers(args)?;
+    let paths = match &args.input {
+        Some(path) if path.as_os_str() == "-" => {
+            return Err(AocError::Usage(String::from("--watch needs an input file, not stdin")));
+        }
+        Some(path) => vec![path.clone()],
+        None => solvers.iter().map(|solver| cache.path(args.year, solver.day())).collect(),
+    };
+    let mut watcher = Watcher::new(paths);
+    let ansi = std::io::stdout().is_terminal();
+    let mut previous = vec![];
+    loop {
+        let inputs = load_inputs(args, &solvers).await;
+        watcher.changed(); // inputs downloaded by this run are not a change
+        let results = solve_days(args, &solvers, inputs)?.into_iter().map(|(result, _)| result).collect::<Vec<DayResult>>();
+        let answers = AnswerStore::load(&args.answers_dir, args.year)?;
+        print!("{}", watch::changes(&previous, &results, &answers, ansi));
+        println!("Waiting for the input to change, press Ctrl-C to stop");
+        previous = results;
+        while !watcher.changed() {
+            tokio::time::sleep(watch::POLL_INTERVAL).await;
+        }
+        println!();
+    }
+}
+
+async fn benchmark(args: &Args) -> Result<(), AocError> {
+    let solvers = selected_solvers(args)?;
+    let mut benches = vec![];
+    // days are measured one after the other so they do not compete for the CPU
+    for (solver, input_puzzle) in solvers.iter().zip(load_inputs(args, &solvers).await) {
+        benches.push(bench::bench(*solver, &input_puzzle?, args.iterations, args.part)?);
+    }
+    bench::print(&benches);
+    Ok(())
+}
+
+/// Solves the days in parallel on a pool of `--threads` threads, in the order of `solvers`. Each result comes with the
+/// first error of its day, if any.
+fn solve_days(
+    args: &Args,
+    solvers: &[&'static dyn Solver],
+    inputs: Vec<Result<String, AocError>>,
+) -> Result<Vec<(DayResult, Option<AocError>)>, AocError> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.threads)
+        .stack_size(SOLVER_STACK_SIZE)
+        .build()
+        .map_err(|err| AocError::Io(format!("failed to start the thread pool: {err}")))?;
+    Ok(pool.install(|| {
+        solvers
+            .par_iter()
+            .zip(inputs)
+            .map(|(solver, input)| solve_day(*solver, input, args))
+            .collect()
+    }))
+}
+
+fn solve_day(solver: &dyn Solver, input: Result<String, AocError>, args: &Args) -> (DayResult, Option<AocError>) {
+    let run = match input.and_then(|input| solver.run(&input, args.part, args.timeout)) {
+        Ok(run) => run,
+        Err(err) => return (DayResult::failed(solver.day(), solver.title(), &err), Some(err)),
+    };
+    let mut errors = vec![];
+    let mut answer = |part: Option<PartRun>| match part {
+        Some(Ok((answer, runtime))) => (Some(answer), Some(runtime)),
+        Some(Err(err)) => {
+            errors.push(err);
+            (None, None)
+        }
+        None => (None, None),
+    };
+    let (part1, part1_runtime) = answer(run.part1);
+    let (part2, part2_runtime) = answer(run.part2);
+    let result = DayResult {
+        day: solver.day(),
+        title: solver.title(),
+        part1,
+        part2,
+        parse_runtime: run.parse,
+        part1_runtime,
+        part2_runtime,
+        status: errors.first().map_or(Status::Ok, Status::of),
+        error: (!errors.is_empty()).then(|| errors.iter().map(ToString::to_string).join("; ")),
+    };
+    (result, errors.into_iter().next())
+}
+
+/// Inputs of the given days, in the same order, downloading at most `MAX_CONCURRENT_REQUESTS` at a time.
+async fn load_inputs(args: &Args, solvers: &[&'static dyn Solver]) -> Vec<Result<String, AocError>> {
+    let cache = InputCache::new(&args.cache_dir);
+    let session = OnceLock::new();
+    stream::iter(solvers)
+        .map(|solver| load_input(args, &cache, &session, solver.day()))
+        .buffered(MAX_CONCURRENT_REQUESTS)
+        .collect()
+        .await
+}
+
+/// `session` is resolved the first time a download is needed and then shared by all days.
+async fn load_input(
+    args: &Args,
+    cache: &InputCache,
+    session: &OnceLock<Result<Option<Session>, AocError>>,
+    day: u8,
+) -> Result<String, AocError> {
+    if let Some(path) = &args.input {
+        return read_input_file(path);
+    }
+    if !args.refresh {
+        if let Some(input) = cache.load(args.year, day)? {
+            return Ok(input);
+        }
+    }
+    let Some(session) = session.get_or_init(|| resolve_session(args)).clone()? else {
+        return Err(AocError::Usage(format!("no cached input for day {day}, a --token is required to download it")));
+    };
+    let input = AocClient::new(&args.base_url, Some(&session.token)).fetch_input(args.year, day).await?;
+    cache.store(args.year, day, &input)?;
+    Ok(input)
+}
+
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    let seconds = value.parse::<f64>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|err| err.to_string())
+}
+
+fn resolve_session(args: &Args) -> Result<Option<Session>, AocError> {
+    let session = args.session.resolve()?;
+    if let (true, Some(session)) = (args.verbose, &session) {
+        eprintln!("Using the session token from {}", session.source);
+    }
+    Ok(session)
+}
+
+fn read_input_file(path: &Path) -> Result<String, AocError> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|err| AocError::Io(format!("failed to read the puzzle input from stdin: {err}")))?;
+        return Ok(input);
+    }
+    std::fs::read_to_string(path)
+        .map_err(|err| AocError::Io(format!("failed to read the puzzle input {}: {err}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::read_input_file;
+
+    #[test]
+    fn read_input_from_file() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "LLR\n").unwrap();
+        assert_eq!(read_input_file(file.path()), Ok(String::from("LLR\n")));
+        assert!(read_input_file(&file.path().with_extension("missing")).is_err());
+    }
+}
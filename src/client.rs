@@ -0,0 +1,111 @@
+use reqwest::header::COOKIE;
+use reqwest::{Method, RequestBuilder, StatusCode};
+use crate::error::AocError;
+use crate::submit::Verdict;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Upper bound on simultaneous requests, to stay polite towards the website.
+pub const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+/// Served instead of the input when the session cookie is missing or expired.
+const LOGGED_OUT_MARKER: &str = "Puzzle inputs differ by user";
+
+/// Talks to the Advent of Code website on behalf of a single session, or anonymously without a token.
+pub struct AocClient {
+    http: reqwest::Client,
+    base_url: String,
+    session_token: Option<String>,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session_token: Option<&str>) -> Self {
+        AocClient {
+            http: reqwest::Client::new(),
+            base_url: base_url.trim_end_matches('/').into(),
+            session_token: session_token.map(String::from),
+        }
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let request = self.http.request(method, format!("{}{path}", self.base_url));
+        match &self.session_token {
+            Some(token) => request.header(COOKIE, format!("session={token}")),
+            None => request,
+        }
+    }
+
+    pub async fn fetch_input(&self, year: u16, day: u8) -> Result<String, AocError> {
+        let response = self.request(Method::GET, &format!("/{year}/day/{day}/input"))
+            .send()
+            .await
+            .map_err(|err| AocError::Network(format!("failed to fetch the puzzle input: {err}")))?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|err| AocError::Network(format!("failed to fetch the puzzle input text: {err}")))?;
+        if body.contains(LOGGED_OUT_MARKER) {
+            return Err(AocError::InvalidSession);
+        }
+        match status {
+            StatusCode::OK => Ok(body),
+            StatusCode::NOT_FOUND => Err(AocError::NotYetUnlocked { year, day }),
+            status => Err(AocError::HttpStatus(status.as_u16())),
+        }
+    }
+
+    /// The puzzle page, which includes the description of part 2 once part 1 is solved by the session.
+    pub async fn fetch_puzzle(&self, year: u16, day: u8) -> Result<String, AocError> {
+        let response = self.request(Method::GET, &format!("/{year}/day/{day}"))
+            .send()
+            .await
+            .map_err(|err| AocError::Network(format!("failed to fetch the puzzle: {err}")))?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|err| AocError::Network(format!("failed to fetch the puzzle text: {err}")))?;
+        match status {
+            StatusCode::OK => Ok(body),
+            StatusCode::NOT_FOUND => Err(AocError::NotYetUnlocked { year, day }),
+            status => Err(AocError::HttpStatus(status.as_u16())),
+        }
+    }
+
+    /// JSON of a private leaderboard. Without access the website redirects to an HTML page instead.
+    pub async fn fetch_leaderboard(&self, year: u16, id: u64) -> Result<String, AocError> {
+        let response = self.request(Method::GET, &format!("/{year}/leaderboard/private/view/{id}.json"))
+            .send()
+            .await
+            .map_err(|err| AocError::Network(format!("failed to fetch the leaderboard: {err}")))?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|err| AocError::Network(format!("failed to fetch the leaderboard: {err}")))?;
+        match status {
+            StatusCode::OK if body.trim_start().starts_with('{') => Ok(body),
+            StatusCode::OK => Err(AocError::InvalidSession),
+            status => Err(AocError::HttpStatus(status.as_u16())),
+        }
+    }
+
+    pub async fn submit_answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, AocError> {
+        let response = self.request(Method::POST, &format!("/{year}/day/{day}/answer"))
+            .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+            .send()
+            .await
+            .map_err(|err| AocError::Network(format!("failed to submit the answer: {err}")))?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|err| AocError::Network(format!("failed to read the submission response: {err}")))?;
+        if status != StatusCode::OK {
+            return Err(AocError::HttpStatus(status.as_u16()));
+        }
+        Verdict::from_html(&body)
+            .ok_or(AocError::UnexpectedResponse(String::from("could not recognize the verdict in the submission response")))
+    }
+}
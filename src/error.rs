@@ -0,0 +1,127 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::time::Duration;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AocError {
+    /// Invalid combination of command line arguments.
+    Usage(String),
+    /// The request to the Advent of Code website did not complete.
+    Network(String),
+    /// The website answered with an unexpected HTTP status.
+    HttpStatus(u16),
+    /// The website answered with a page that could not be understood.
+    UnexpectedResponse(String),
+    NotYetUnlocked { year: u16, day: u8 },
+    InvalidSession,
+    /// The puzzle input is malformed, `line` and `column` are 1-based.
+    Parse { day: u8, line: usize, column: Option<usize>, msg: String },
+    /// The puzzle input is well-formed but has no solution.
+    Unsolvable { day: u8, msg: String },
+    /// A solver panicked, `msg` is the panic message.
+    Panicked { day: u8, msg: String },
+    /// A part did not finish within `--timeout`.
+    TimedOut { day: u8, part: u8, timeout: Duration },
+    /// A local file (cache, answers, ...) could not be read or written.
+    Io(String),
+    /// `--check` found answers that differ from the accepted ones.
+    AnswerMismatch(usize),
+    /// `--fail-on-regression` found parts that got slower than their baseline.
+    Regression(usize),
+}
+
+impl AocError {
+    pub fn parse(day: u8, line: usize, msg: impl Into<String>) -> Self {
+        AocError::Parse { day, line, column: None, msg: msg.into() }
+    }
+
+    pub fn parse_at(day: u8, line: usize, column: usize, msg: impl Into<String>) -> Self {
+        AocError::Parse { day, line, column: Some(column), msg: msg.into() }
+    }
+
+    pub fn unsolvable(day: u8, msg: impl Into<String>) -> Self {
+        AocError::Unsolvable { day, msg: msg.into() }
+    }
+
+    /// Process exit code, distinct for every kind of failure.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            AocError::AnswerMismatch(_) => 1,
+            AocError::Usage(_) => 2,
+            AocError::Network(_) => 3,
+            AocError::HttpStatus(_) => 4,
+            AocError::UnexpectedResponse(_) => 5,
+            AocError::NotYetUnlocked { .. } => 6,
+            AocError::InvalidSession => 7,
+            AocError::Parse { .. } => 8,
+            AocError::Unsolvable { .. } => 9,
+            AocError::Io(_) => 10,
+            AocError::Panicked { .. } => 11,
+            AocError::TimedOut { .. } => 12,
+            AocError::Regression(_) => 13,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Usage(msg) => write!(f, "{msg}"),
+            AocError::Network(msg) => write!(f, "network error: {msg}"),
+            AocError::HttpStatus(status) => write!(f, "request failed with HTTP {status}"),
+            AocError::UnexpectedResponse(msg) => write!(f, "unexpected response: {msg}"),
+            AocError::NotYetUnlocked { year, day } => write!(f, "day {day} of {year} is not unlocked yet"),
+            AocError::InvalidSession => write!(f, "the session token is invalid or has expired"),
+            AocError::Parse { day, line, column: None, msg } => write!(f, "day {day}: invalid input on line {line}: {msg}"),
+            AocError::Parse { day, line, column: Some(column), msg } => {
+                write!(f, "day {day}: invalid input on line {line}, column {column}: {msg}")
+            }
+            AocError::Unsolvable { day, msg } => write!(f, "day {day}: no solution: {msg}"),
+            AocError::Panicked { day, msg } => write!(f, "day {day}: the solver panicked: {msg}"),
+            AocError::TimedOut { day, part, timeout } => write!(f, "day {day} part {part}: timed out after {timeout:?}"),
+            AocError::Io(msg) => write!(f, "{msg}"),
+            AocError::AnswerMismatch(count) => write!(f, "{count} answer(s) differ from the accepted answers"),
+            AocError::Regression(count) => write!(f, "{count} part(s) got slower than their baseline"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::time::Duration;
+    use crate::error::AocError;
+
+    #[test]
+    fn exit_codes_are_distinct() {
+        let errors = [
+            AocError::AnswerMismatch(1),
+            AocError::Usage(String::new()),
+            AocError::Network(String::new()),
+            AocError::HttpStatus(500),
+            AocError::UnexpectedResponse(String::new()),
+            AocError::NotYetUnlocked { year: 2023, day: 25 },
+            AocError::InvalidSession,
+            AocError::parse(1, 1, ""),
+            AocError::unsolvable(1, ""),
+            AocError::Io(String::new()),
+            AocError::Panicked { day: 1, msg: String::new() },
+            AocError::TimedOut { day: 1, part: 1, timeout: Duration::from_secs(1) },
+            AocError::Regression(1),
+        ];
+        let codes = errors.iter().map(AocError::exit_code).collect::<HashSet<u8>>();
+        assert_eq!(codes.len(), errors.len());
+        assert!(!codes.contains(&0));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(AocError::parse(6, 2, "expected `Distance:`").to_string(), "day 6: invalid input on line 2: expected `Distance:`");
+        assert_eq!(
+            AocError::parse_at(2, 3, 9, "expected a number, found `x`").to_string(),
+            "day 2: invalid input on line 3, column 9: expected a number, found `x`"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use crate::cache::write_atomic;
+use crate::error::AocError;
+use crate::puzzle::Example;
+
+/// Example fixtures stored as `{root}/{year}/day{day:02}/part{part}.txt`, with the expected answer in `part{part}.expected`.
+pub struct ExampleStore {
+    root: PathBuf,
+}
+
+impl ExampleStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        ExampleStore { root: root.into() }
+    }
+
+    pub fn input_path(&self, year: u16, day: u8, part: u8) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("day{day:02}")).join(format!("part{part}.txt"))
+    }
+
+    pub fn answer_path(&self, year: u16, day: u8, part: u8) -> PathBuf {
+        self.input_path(year, day, part).with_extension("expected")
+    }
+
+    /// Examples of both parts of a day, a part without an input file has none.
+    pub fn load(&self, year: u16, day: u8) -> Result<Vec<Example>, AocError> {
+        let mut examples = vec![];
+        for part in 1..=2 {
+            let Some(input) = read(&self.input_path(year, day, part))? else { continue };
+            let answer = read(&self.answer_path(year, day, part))?.map(|answer| answer.trim().to_string());
+            examples.push(Example { part, input, answer });
+        }
+        Ok(examples)
+    }
+
+    /// Writes the input and, if known, the answer of an example.
+    pub fn store(&self, year: u16, day: u8, example: &Example) -> Result<(), AocError> {
+        write(&self.input_path(year, day, example.part), &example.input)?;
+        match &example.answer {
+            Some(answer) => write(&self.answer_path(year, day, example.part), &format!("{answer}\n")),
+            None => Ok(()),
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<Option<String>, AocError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(AocError::Io(format!("failed to read example {}: {err}", path.display()))),
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), AocError> {
+    write_atomic(path, contents).map_err(|err| AocError::Io(format!("failed to write example {}: {err}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::PuzzleResult;
+    use crate::aoc::YEARS;
+    use crate::examples::ExampleStore;
+    use crate::puzzle::Example;
+
+    #[test]
+    fn store_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ExampleStore::new(dir.path());
+        let example = Example { part: 2, input: String::from("1 2\n"), answer: Some(String::from("3")) };
+        store.store(2023, 7, &example).unwrap();
+        store.store(2023, 7, &Example { part: 1, input: String::from("1\n"), answer: None }).unwrap();
+        assert_eq!(std::fs::read_to_string(dir.path().join("2023/day07/part2.expected")).unwrap(), "3\n");
+        assert_eq!(store.load(2023, 7).unwrap(), vec![Example { part: 1, input: String::from("1\n"), answer: None }, example]);
+        assert_eq!(store.load(2023, 8).unwrap(), vec![]);
+        assert_eq!(store.load(2022, 7).unwrap(), vec![]);
+    }
+
+    /// Runs every registered solver over the fixtures in `examples/`.
+    #[test]
+    fn registered_solvers_solve_their_examples() {
+        let store = ExampleStore::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+        let mut failures = vec![];
+        for (year, solver) in YEARS.iter().flat_map(|year| year.solvers.iter().map(|solver| (year.year, solver))) {
+            for example in store.load(year, solver.day()).unwrap() {
+                let Some(expected) = example.answer else { continue };
+                let answer = match example.part {
+                    1 => solver.part1(&example.input),
+                    _ => solver.part2(&example.input),
+                };
+                match answer {
+                    Ok(answer) if expected.parse::<PuzzleResult>().is_ok_and(|expected| expected == answer) => {}
+                    answer => failures.push(format!("{year} day {} part {}: expected {expected}, got {answer:?}", solver.day(), example.part)),
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,277 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use crate::bench::Stats;
+use crate::error::AocError;
+use crate::report::DayResult;
+
+/// Number of most recent runs on the same machine whose median is the baseline of a part.
+const BASELINE_RUNS: usize = 5;
+
+/// Where and when a run happened.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tags {
+    pub timestamp: String,
+    pub commit: Option<String>,
+    pub machine: String,
+}
+
+impl Tags {
+    pub fn current() -> Self {
+        Tags {
+            timestamp: OffsetDateTime::now_utc()
+                .replace_nanosecond(0)
+                .ok()
+                .and_then(|now| now.format(&Rfc3339).ok())
+                .unwrap_or_default(),
+            commit: command_output("git", &["rev-parse", "--short", "HEAD"]),
+            machine: machine_name(),
+        }
+    }
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !stdout.trim().is_empty()).then(|| stdout.trim().to_string())
+}
+
+fn machine_name() -> String {
+    fs::read_to_string("/proc/sys/kernel/hostname")
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .or_else(|| command_output("hostname", &[]))
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+/// Timings of one day in one run, a line of the history file.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub timestamp: String,
+    pub commit: Option<String>,
+    pub machine: String,
+    pub day: u8,
+    pub parse_ns: u64,
+    pub part1_ns: Option<u64>,
+    pub part2_ns: Option<u64>,
+}
+
+impl Entry {
+    /// `None` for a day without any timed part.
+    pub fn new(tags: &Tags, result: &DayResult) -> Option<Self> {
+        if result.part1_runtime.is_none() && result.part2_runtime.is_none() {
+            return None;
+        }
+        Some(Entry {
+            timestamp: tags.timestamp.clone(),
+            commit: tags.commit.clone(),
+            machine: tags.machine.clone(),
+            day: result.day,
+            parse_ns: result.parse_runtime.as_nanos() as u64,
+            part1_ns: result.part1_runtime.map(|x| x.as_nanos() as u64),
+            part2_ns: result.part2_runtime.map(|x| x.as_nanos() as u64),
+        })
+    }
+
+    fn part(&self, part: u8) -> Option<Duration> {
+        match part {
+            1 => self.part1_ns,
+            _ => self.part2_ns,
+        }.map(Duration::from_nanos)
+    }
+}
+
+/// A part that took longer than its baseline by more than the allowed percentage.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub part: u8,
+    pub runtime: Duration,
+    pub baseline: Duration,
+}
+
+impl Regression {
+    pub fn percent(&self) -> f64 {
+        (self.runtime.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f, "Day {:02} part {}: {:.2?} is {:.0}% slower than the baseline of {:.2?}",
+            self.day, self.part, self.runtime, self.percent(), self.baseline
+        )
+    }
+}
+
+/// Runtimes of every recorded run of one year, stored as `{dir}/{year}.jsonl` with one line per day and run.
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl History {
+    pub fn load(dir: &Path, year: u16) -> Result<Self, AocError> {
+        let path = dir.join(format!("{year}.jsonl"));
+        let entries = match fs::read_to_string(&path) {
+            Ok(text) => text
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(i, line)| serde_json::from_str::<Entry>(line).map_err(|err| {
+                    AocError::Io(format!("invalid history file {} on line {}: {err}", path.display(), i + 1))
+                }))
+                .collect::<Result<Vec<Entry>, AocError>>()?,
+            Err(err) if err.kind() == ErrorKind::NotFound => vec![],
+            Err(err) => return Err(AocError::Io(format!("failed to read history file {}: {err}", path.display()))),
+        };
+        Ok(History { path, entries })
+    }
+
+    /// Entries of a day, oldest first.
+    pub fn day(&self, day: u8) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |entry| entry.day == day)
+    }
+
+    /// Median runtime of the last `BASELINE_RUNS` runs of a part on the given machine.
+    pub fn baseline(&self, day: u8, part: u8, machine: &str) -> Option<Duration> {
+        let mut runtimes = self.day(day)
+            .filter(|entry| entry.machine == machine)
+            .filter_map(|entry| entry.part(part))
+            .collect::<Vec<Duration>>();
+        runtimes.drain(..runtimes.len().saturating_sub(BASELINE_RUNS));
+        (!runtimes.is_empty()).then(|| Stats::new(&runtimes).median)
+    }
+
+    /// Parts of `results` that are more than `percent` slower than their baseline on `machine`.
+    pub fn regressions(&self, results: &[DayResult], machine: &str, percent: f64) -> Vec<Regression> {
+        results
+            .iter()
+            .flat_map(|result| [(1, result.part1_runtime), (2, result.part2_runtime)].map(|(part, runtime)| (result.day, part, runtime)))
+            .filter_map(|(day, part, runtime)| {
+                let regression = Regression { day, part, runtime: runtime?, baseline: self.baseline(day, part, machine)? };
+                (regression.percent() > percent).then_some(regression)
+            })
+            .collect()
+    }
+
+    /// Appends the entries to the file in a single write, so concurrent runs do not interleave their lines.
+    pub fn append(&mut self, entries: Vec<Entry>) -> Result<(), AocError> {
+        if entries.is_empty() { return Ok(()); }
+        let mut text = String::new();
+        for entry in &entries {
+            text += &serde_json::to_string(entry).map_err(|err| AocError::Io(format!("failed to serialize history: {err}")))?;
+            text += "\n";
+        }
+        let write = || -> std::io::Result<()> {
+            if let Some(dir) = self.path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::OpenOptions::new().create(true).append(true).open(&self.path)?.write_all(text.as_bytes())
+        };
+        write().map_err(|err| AocError::Io(format!("failed to write history file {}: {err}", self.path.display())))?;
+        self.entries.extend(entries);
+        Ok(())
+    }
+}
+
+/// Prints the last `limit` runs of a day, with the change of every part relative to the run before it.
+pub fn print(history: &History, day: u8, limit: usize) {
+    let entries = history.day(day).collect::<Vec<&Entry>>();
+    println!("{:<20} {:<10} {:<16} {:>12} {:>20} {:>20}", "Timestamp", "Commit", "Machine", "Parse", "Part 1", "Part 2");
+    for (i, entry) in entries.iter().enumerate().skip(entries.len().saturating_sub(limit)) {
+        let previous = i.checked_sub(1).map(|i| entries[i]);
+        let part = |part: u8| {
+            let Some(runtime) = entry.part(part) else { return String::from("-") };
+            match previous.and_then(|previous| previous.part(part)) {
+                Some(before) if !before.is_zero() => {
+                    format!("{runtime:.2?} ({:+.0}%)", (runtime.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0)
+                }
+                _ => format!("{runtime:.2?}"),
+            }
+        };
+        println!(
+            "{:<20} {:<10} {:<16} {:>12} {:>20} {:>20}",
+            entry.timestamp, entry.commit.as_deref().unwrap_or("-"), entry.machine,
+            format!("{:.2?}", Duration::from_nanos(entry.parse_ns)), part(1), part(2)
+        );
+    }
+    if entries.is_empty() {
+        println!("No recorded runs of day {day} in {}", history.path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::history::{Entry, History, Regression, Tags};
+    use crate::report::{DayResult, Status};
+
+    fn result(day: u8, part1: u64, part2: Option<u64>) -> DayResult {
+        DayResult {
+            day,
+            title: "",
+            part1: None,
+            part2: None,
+            parse_runtime: Duration::from_micros(1),
+            part1_runtime: Some(Duration::from_millis(part1)),
+            part2_runtime: part2.map(Duration::from_millis),
+            status: Status::Ok,
+            error: None,
+        }
+    }
+
+    fn tags(machine: &str) -> Tags {
+        Tags { timestamp: String::from("2023-12-05T06:00:00Z"), commit: Some(String::from("abc1234")), machine: machine.into() }
+    }
+
+    #[test]
+    fn append_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut history = History::load(dir.path(), 2023).unwrap();
+        history.append(vec![Entry::new(&tags("box"), &result(5, 10, None)).unwrap()]).unwrap();
+        history.append(vec![Entry::new(&tags("box"), &result(5, 12, Some(3))).unwrap()]).unwrap();
+        let text = std::fs::read_to_string(dir.path().join("2023.jsonl")).unwrap();
+        assert_eq!(
+            text.lines().next().unwrap(),
+            r#"{"timestamp":"2023-12-05T06:00:00Z","commit":"abc1234","machine":"box","day":5,"parse_ns":1000,"part1_ns":10000000,"part2_ns":null}"#
+        );
+        let history = History::load(dir.path(), 2023).unwrap();
+        assert_eq!(history.day(5).count(), 2);
+        assert_eq!(history.day(6).count(), 0);
+    }
+
+    #[test]
+    fn regressions() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut history = History::load(dir.path(), 2023).unwrap();
+        // the oldest run falls out of the baseline window, the other machine is never part of it
+        for (machine, part1) in [("box", 100), ("box", 10), ("box", 10), ("box", 12), ("box", 8), ("box", 11), ("laptop", 50)] {
+            history.append(vec![Entry::new(&tags(machine), &result(5, part1, Some(20))).unwrap()]).unwrap();
+        }
+        assert_eq!(history.baseline(5, 1, "box"), Some(Duration::from_millis(10)));
+        assert_eq!(history.baseline(5, 2, "box"), Some(Duration::from_millis(20)));
+        assert_eq!(history.baseline(6, 1, "box"), None);
+
+        let regressions = history.regressions(&[result(5, 13, Some(21)), result(6, 100, None)], "box", 20.0);
+        assert_eq!(regressions, vec![Regression { day: 5, part: 1, runtime: Duration::from_millis(13), baseline: Duration::from_millis(10) }]);
+        assert_eq!(regressions[0].to_string(), "Day 05 part 1: 13.00ms is 30% slower than the baseline of 10.00ms");
+    }
+
+    #[test]
+    fn invalid_line() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("2023.jsonl"), "{\"day\": 1}\n").unwrap();
+        assert!(History::load(dir.path(), 2023).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+use serde::Deserialize;
+use time::OffsetDateTime;
+use crate::error::AocError;
+
+/// The website asks to download a private leaderboard at most once every 15 minutes.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard as returned by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub last_star_ts: i64,
+    /// Stars by day and part, both as strings.
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// Unix time at which the member got the star of a part.
+    pub fn star(&self, day: u8, part: u8) -> Option<i64> {
+        Some(self.completion_day_level.get(&day.to_string())?.get(&part.to_string())?.get_star_ts)
+    }
+
+    fn star_index(&self, day: u8, part: u8) -> u64 {
+        self.completion_day_level
+            .get(&day.to_string())
+            .and_then(|parts| parts.get(&part.to_string()))
+            .map_or(0, |star| star.star_index)
+    }
+}
+
+/// A member with the local score recomputed from the star timestamps.
+pub struct Standing<'a> {
+    pub rank: usize,
+    pub member: &'a Member,
+    pub score: u64,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, AocError> {
+        serde_json::from_str(json).map_err(|err| AocError::UnexpectedResponse(format!("invalid leaderboard: {err}")))
+    }
+
+    /// Local scores by member id: for every star, the first member to get it earns as many points as there are
+    /// members, the second one point less and so on.
+    pub fn local_scores(&self) -> BTreeMap<u64, u64> {
+        let mut scores = self.members.values().map(|member| (member.id, 0)).collect::<BTreeMap<u64, u64>>();
+        let members = self.members.len() as u64;
+        for day in 1..=25 {
+            for part in 1..=2 {
+                let mut finishers = self.members
+                    .values()
+                    .filter_map(|member| Some((member.star(day, part)?, member.star_index(day, part), member.id)))
+                    .collect::<Vec<(i64, u64, u64)>>();
+                finishers.sort();
+                for (position, (_, _, id)) in finishers.into_iter().enumerate() {
+                    *scores.entry(id).or_default() += members - position as u64;
+                }
+            }
+        }
+        scores
+    }
+
+    /// Members by local score, ties go to the one with more stars and then to the one who got the last star first.
+    pub fn standings(&self) -> Vec<Standing<'_>> {
+        let scores = self.local_scores();
+        let mut members = self.members.values().collect::<Vec<&Member>>();
+        members.sort_by_key(|member| (std::cmp::Reverse((scores[&member.id], member.stars)), member.last_star_ts, member.id));
+        members
+            .into_iter()
+            .enumerate()
+            .map(|(i, member)| Standing { rank: i + 1, member, score: scores[&member.id] })
+            .collect()
+    }
+
+    /// Last day on which any member got a star.
+    fn last_day(&self) -> u8 {
+        (1..=25).rev().find(|&day| self.members.values().any(|member| member.star(day, 1).is_some())).unwrap_or(0)
+    }
+}
+
+/// Ranked table of the members with a column per day, `*` for both stars and `+` for only the first one. With `day`
+/// it is followed by the times at which the members got the stars of that day.
+pub fn render(leaderboard: &Leaderboard, day: Option<u8>) -> String {
+    let standings = leaderboard.standings();
+    let width = standings.iter().map(|standing| standing.member.display_name().len()).max().unwrap_or(0).max(4);
+    let days = (1..=leaderboard.last_day()).map(|day| char::from(b'0' + day % 10)).collect::<String>();
+    let mut table = format!("{:>4} {:<width$} {:>5} {:>5}  {days}\n", "Rank", "Name", "Score", "Stars");
+    for standing in &standings {
+        let member = standing.member;
+        let stars = (1..=leaderboard.last_day())
+            .map(|day| match (member.star(day, 1), member.star(day, 2)) {
+                (Some(_), Some(_)) => '*',
+                (Some(_), None) => '+',
+                _ => '.',
+            })
+            .collect::<String>();
+        table += &format!("{:>4} {:<width$} {:>5} {:>5}  {stars}\n", standing.rank, member.display_name(), standing.score, member.stars);
+    }
+    if let Some(day) = day {
+        table += &format!("\n{:<width$} {:>14} {:>14} {:>10}\n", format!("Day {day}"), "Part 1", "Part 2", "Delta");
+        let mut finishers = standings
+            .iter()
+            .filter_map(|standing| Some((standing.member, standing.member.star(day, 1)?, standing.member.star(day, 2))))
+            .collect::<Vec<(&Member, i64, Option<i64>)>>();
+        finishers.sort_by_key(|&(_, part1, part2)| (part2.is_none(), part2, part1));
+        for (member, part1, part2) in finishers {
+            table += &format!(
+                "{:<width$} {:>14} {:>14} {:>10}\n",
+                member.display_name(), timestamp(part1),
+                part2.map_or_else(|| String::from("-"), timestamp),
+                part2.map_or_else(|| String::from("-"), |part2| delta(part2 - part1)),
+            );
+        }
+    }
+    table
+}
+
+/// `MM-DD hh:mm:ss` in UTC.
+fn timestamp(unix: i64) -> String {
+    match OffsetDateTime::from_unix_timestamp(unix) {
+        Ok(t) => format!("{:02}-{:02} {:02}:{:02}:{:02}", u8::from(t.month()), t.day(), t.hour(), t.minute(), t.second()),
+        Err(_) => String::from("?"),
+    }
+}
+
+fn delta(seconds: i64) -> String {
+    format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leaderboard::{render, Leaderboard};
+
+    const JSON: &str = include_str!("../tests/fixtures/leaderboard/2023.json");
+
+    #[test]
+    fn local_scores() {
+        let leaderboard = Leaderboard::parse(JSON).unwrap();
+        let scores = leaderboard.local_scores();
+        assert_eq!(scores.into_iter().collect::<Vec<(u64, u64)>>(), vec![(101, 15), (102, 12), (103, 3), (104, 0)]);
+        let ranking = leaderboard.standings().iter().map(|x| (x.rank, x.member.display_name())).collect::<Vec<_>>();
+        assert_eq!(ranking[0], (1, String::from("Alice")));
+        assert_eq!(ranking[2], (3, String::from("(anonymous user #103)")));
+    }
+
+    #[test]
+    fn table() {
+        let leaderboard = Leaderboard::parse(JSON).unwrap();
+        assert_eq!(render(&leaderboard, None), "\
+Rank Name                  Score Stars  12
+   1 Alice                    15     4  **
+   2 Bob                      12     4  **
+   3 (anonymous user #103)     3     1  +.
+   4 Carol                     0     0  ..
+");
+        let day = render(&leaderboard, Some(1));
+        assert!(day.ends_with("
+Day 1                         Part 1         Part 2      Delta
+Alice                 12-01 05:04:10 12-01 05:09:00    0:04:50
+Bob                   12-01 05:06:40 12-01 05:53:20    0:46:40
+(anonymous user #103) 12-01 05:05:00              -          -
+"), "{day}");
+    }
+
+    #[test]
+    fn not_a_leaderboard() {
+        assert!(Leaderboard::parse("<!DOCTYPE html>").is_err());
+    }
+}
@@ -0,0 +1,35 @@
+//! Solutions of the [Advent of Code 2023](https://adventofcode.com/2023) and the command line tool around them.
+//!
+//! Every day is registered by year in [`YEARS`] and solves a puzzle input given as text:
+//!
+//! ```
+//! use aoc2023::PuzzleResult;
+//!
+//! let day01 = aoc2023::solver(2023, 1).unwrap();
+//! assert_eq!(day01.part1("1abc2\npqr3stu8vwx"), Ok(PuzzleResult::Number(50)));
+//! ```
+//!
+//! The days of a year are in `aoc::yYYYY::dayNN`, the grid and geometry types they share are in [`aoc::util`].
+
+pub mod aoc;
+pub mod cli;
+pub mod error;
+mod answers;
+mod bench;
+mod cache;
+mod client;
+mod examples;
+mod history;
+mod leaderboard;
+mod puzzle;
+mod report;
+mod scaffold;
+mod server;
+mod submit;
+mod token;
+mod watch;
+
+pub use aoc::common::{Part, PartRun, Puzzle, PuzzleResult, Run, Solver};
+pub use aoc::generate::Rng;
+pub use aoc::{solver, solvers, Year, YEARS};
+pub use error::AocError;
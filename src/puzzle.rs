@@ -0,0 +1,388 @@
+use crate::error::AocError;
+
+/// Column at which paragraphs and list items are wrapped.
+const WIDTH: usize = 80;
+
+const ARTICLE_START: &str = "<article class=\"day-desc\">";
+
+const ARTICLE_END: &str = "</article>";
+
+/// Inner HTML of the `<article class="day-desc">` blocks of a puzzle page, one per unlocked part.
+pub fn descriptions(page: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = page;
+    while let Some(start) = rest.find(ARTICLE_START) {
+        rest = &rest[start + ARTICLE_START.len()..];
+        let end = rest.find(ARTICLE_END).unwrap_or(rest.len());
+        articles.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    articles
+}
+
+/// Title of the puzzle, taken from the `--- Day N: Title ---` heading of part 1.
+pub fn title(page: &str) -> Option<String> {
+    let heading = descriptions(page).first()?.split("</h2>").next()?;
+    let text = tokens(heading)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect::<String>();
+    let (_, title) = text.trim().strip_prefix("--- Day ")?.strip_suffix(" ---")?.split_once(": ")?;
+    Some(title.to_string())
+}
+
+/// Converts the puzzle descriptions of a page to terminal text. With `ansi` emphasis is bold and code is colored,
+/// without it they are marked as `*emphasis*` and `` `code` ``.
+pub fn render(page: &str, ansi: bool) -> Result<String, AocError> {
+    let articles = descriptions(page);
+    if articles.is_empty() {
+        return Err(AocError::UnexpectedResponse(String::from("the page contains no puzzle description")));
+    }
+    let mut renderer = Renderer { ansi, ..Renderer::default() };
+    for article in articles {
+        for token in tokens(article) {
+            renderer.token(token);
+        }
+        renderer.flush_block();
+    }
+    Ok(renderer.out.trim_end().to_string() + "\n")
+}
+
+/// Example input of one part as given in its description, with the answer the description gives for it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+/// Takes the first `<pre><code>` block of every part as its example input, or the one of the part before if it has
+/// none, and the last emphasized code (`<code><em>`) outside of blocks as the answer.
+pub fn examples(page: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    for (part, article) in (1..).zip(descriptions(page)) {
+        let (mut input, mut answer) = (None, None);
+        let (mut pre, mut code, mut emphasized) = (false, false, false);
+        let mut text = String::new();
+        for token in tokens(article) {
+            match token {
+                Token::Open("pre") => pre = true,
+                Token::Close("pre") => pre = false,
+                Token::Open("code") => {
+                    code = true;
+                    emphasized = false;
+                    text.clear();
+                }
+                Token::Open("em") if code && !pre => emphasized = true,
+                Token::Text(t) if code => text += &t,
+                Token::Close("code") if pre => {
+                    code = false;
+                    input.get_or_insert_with(|| text.clone());
+                }
+                Token::Close("code") => {
+                    code = false;
+                    if emphasized && !text.trim().is_empty() {
+                        answer = Some(text.trim().to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(input) = input.or_else(|| examples.last().map(|example| example.input.clone())) {
+            examples.push(Example { part, input, answer });
+        }
+    }
+    examples
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Token<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    Text(String),
+}
+
+/// Splits HTML into tags and decoded text. Attributes are dropped, tag names are lowercase.
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(decode(rest)));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(decode(&rest[..start])));
+        }
+        let end = rest[start..].find('>').map_or(rest.len(), |end| start + end + 1);
+        let tag = rest[start + 1..end].trim_end_matches('>').trim_end_matches('/');
+        let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+        match tag.strip_prefix('/') {
+            Some(name) => tokens.push(Token::Close(name.trim())),
+            None if tag.starts_with('!') => {} // comment or doctype
+            None => tokens.push(Token::Open(&tag[..name_end])),
+        }
+        rest = &rest[end..];
+    }
+    tokens
+}
+
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded += &rest[..start];
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|&end| end <= 10) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let c = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16).ok())
+                .unwrap_or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse::<u32>().ok()))
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded + rest
+}
+
+#[derive(Default)]
+struct Renderer {
+    ansi: bool,
+    out: String,
+    /// Inline content of the current paragraph, heading or list item.
+    block: String,
+    pre: bool,
+    emphasis: usize,
+    code: usize,
+    list_item: bool,
+}
+
+impl Renderer {
+    fn token(&mut self, token: Token) {
+        match token {
+            Token::Text(text) if self.pre => self.block += &text,
+            Token::Text(text) => {
+                for (i, word) in text.split(|c: char| c.is_ascii_whitespace()).enumerate() {
+                    if i > 0 && !self.block.ends_with(' ') && !self.block.is_empty() {
+                        self.block.push(' ');
+                    }
+                    self.block += word;
+                }
+            }
+            Token::Open("p" | "h2" | "ul" | "ol") => self.flush_block(),
+            Token::Open("li") => {
+                self.flush_block();
+                self.list_item = true;
+            }
+            Token::Open("pre") => {
+                self.flush_block();
+                self.pre = true;
+            }
+            Token::Open("em") => {
+                self.emphasis += 1;
+                self.style(self.emphasis == 1 && self.code == 0, "*");
+            }
+            Token::Open("code") => {
+                self.code += 1;
+                self.style(self.code == 1 && !self.pre, "`");
+            }
+            Token::Close("em") => {
+                self.emphasis = self.emphasis.saturating_sub(1);
+                self.style(self.emphasis == 0 && self.code == 0, "*");
+            }
+            Token::Close("code") => {
+                self.code = self.code.saturating_sub(1);
+                self.style(self.code == 0 && !self.pre, "`");
+            }
+            Token::Close("h2") => {
+                let heading = self.block.trim().to_string();
+                self.block.clear();
+                self.out += &if self.ansi { format!("\x1b[1;32m{heading}\x1b[0m") } else { heading };
+                self.out += "\n\n";
+            }
+            Token::Close("pre") => {
+                for line in self.block.trim_end_matches('\n').lines() {
+                    self.out += &format!("    {line}\n");
+                }
+                self.out += "\n";
+                self.block.clear();
+                self.pre = false;
+            }
+            Token::Close("p" | "li") => self.flush_block(),
+            Token::Close("ul" | "ol") => self.out += "\n",
+            Token::Open(_) | Token::Close(_) => {}
+        }
+    }
+
+    /// Switches the text style after `<em>` or `<code>` opened or closed, `marker` stands in for it without ANSI.
+    fn style(&mut self, outermost: bool, marker: &str) {
+        if self.ansi {
+            let mut codes = vec!["0"];
+            if self.emphasis > 0 { codes.push("1"); }
+            if self.code > 0 { codes.push("36"); }
+            self.block += &format!("\x1b[{}m", codes.join(";"));
+        } else if outermost {
+            self.block += marker;
+        }
+    }
+
+    /// Wraps the current block into the output, list items get a bullet and a hanging indent.
+    fn flush_block(&mut self) {
+        let text = std::mem::take(&mut self.block);
+        let text = text.trim();
+        if visible_len(text) == 0 {
+            self.list_item = false;
+            return;
+        }
+        let (first, other) = if self.list_item { ("  - ", "    ") } else { ("", "") };
+        let mut line = String::from(first);
+        let mut width = first.len();
+        for word in text.split(' ') {
+            if width > first.len().max(other.len()) && width + 1 + visible_len(word) > WIDTH {
+                self.out += line.trim_end();
+                self.out += "\n";
+                line = String::from(other);
+                width = other.len();
+            } else if width > first.len().max(other.len()) {
+                line.push(' ');
+                width += 1;
+            }
+            line += word;
+            width += visible_len(word);
+        }
+        self.out += line.trim_end();
+        self.out += if self.list_item { "\n" } else { "\n\n" };
+        self.list_item = false;
+    }
+}
+
+/// Number of characters that take up space in the terminal, skipping ANSI escape sequences.
+fn visible_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut escape = false;
+    for c in text.chars() {
+        match (escape, c) {
+            (false, '\x1b') => escape = true,
+            (false, _) => len += 1,
+            (true, 'm') => escape = false,
+            (true, _) => {}
+        }
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{decode, descriptions, examples, render, title, visible_len, Example};
+
+    const PART1: &str = include_str!("../tests/fixtures/puzzle/part1.html");
+    const BOTH: &str = include_str!("../tests/fixtures/puzzle/both.html");
+
+    #[test]
+    fn articles() {
+        assert_eq!(descriptions(PART1).len(), 1);
+        assert_eq!(descriptions(BOTH).len(), 2);
+        assert!(descriptions(BOTH)[1].starts_with("<h2 id=\"part2\">--- Part Two ---</h2>"));
+        assert!(descriptions("<html><body>Not found</body></html>").is_empty());
+    }
+
+    #[test]
+    fn titles() {
+        assert_eq!(title(PART1), Some(String::from("Trebuchet?!")));
+        assert_eq!(title("<article class=\"day-desc\"><h2>--- Day 16: The Floor &amp; Lava ---</h2></article>"), Some(String::from("The Floor & Lava")));
+        assert_eq!(title("<article class=\"day-desc\"><h2>Welcome</h2></article>"), None);
+    }
+
+    #[test]
+    fn entities() {
+        assert_eq!(decode("a &amp; b &lt;c&gt; &quot;d&quot; &#8230; &#x2603; & e;"), "a & b <c> \"d\" \u{2026} \u{2603} & e;");
+    }
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(render(PART1, false).unwrap(), "\
+--- Day 1: Trebuchet?! ---
+
+The elves have a *calibration document* that needs fixing & they are <very>
+worried.
+
+Each line once contained a specific *calibration value* that the elves now need
+to recover. For example:
+
+    1abc2
+    pqr3stu8vwx
+    a1b2c3d4e5f
+    treb7uchet
+
+In this example, the calibration values of these four lines are:
+
+  - `12` for the first line,
+  - `38`, `15` and `77` for the others, which is quite a long list item that
+    will certainly need to wrap onto a second line.
+
+Adding these together produces `142`.
+
+Consider your entire calibration document. *What is the sum of all of the
+calibration values?*
+");
+    }
+
+    #[test]
+    fn part_two() {
+        let text = render(BOTH, false).unwrap();
+        assert!(text.contains("\n--- Part Two ---\n\nSome of the digits are actually *spelled out with letters*: `one`,"), "{text}");
+        assert!(text.contains("`three`\u{2026} also count as valid \"digits\"."), "{text}");
+        assert!(text.ends_with("    two1nine\n    eightwothree\n\n*What is the sum of all of the calibration values?*\n"), "{text}");
+        assert!(!text.contains("Your puzzle answer was"));
+    }
+
+    #[test]
+    fn ansi() {
+        let text = render(PART1, true).unwrap();
+        assert!(text.starts_with("\x1b[1;32m--- Day 1: Trebuchet?! ---\x1b[0m\n"));
+        assert!(text.contains("produces \x1b[0;36m\x1b[0;1;36m142\x1b[0;36m\x1b[0m."), "{text:?}");
+        assert!(text.lines().all(|line| visible_len(line) <= 80));
+    }
+
+    #[test]
+    fn example_inputs_and_answers() {
+        let input = String::from("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n");
+        assert_eq!(examples(PART1), vec![Example { part: 1, input: input.clone(), answer: Some(String::from("142")) }]);
+        assert_eq!(examples(BOTH), vec![
+            Example { part: 1, input, answer: Some(String::from("142")) },
+            Example { part: 2, input: String::from("two1nine\neightwothree\n"), answer: None },
+        ]);
+        let reused = "<article class=\"day-desc\"><pre><code>1 2\n</code></pre><p><code><em>3</em></code></p></article>\
+            <article class=\"day-desc\"><p>Now it is <code><em>6</em></code> instead of <code>3</code>.</p></article>";
+        assert_eq!(examples(reused)[1], Example { part: 2, input: String::from("1 2\n"), answer: Some(String::from("6")) });
+    }
+
+    #[test]
+    fn no_description() {
+        assert!(render("<html><body>Please log in</body></html>", false).is_err());
+    }
+}
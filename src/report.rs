@@ -0,0 +1,216 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::time::Duration;
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use crate::aoc::common::PuzzleResult;
+use crate::error::AocError;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+    Timeout,
+}
+
+impl Status {
+    pub fn of(err: &AocError) -> Self {
+        match err {
+            AocError::TimedOut { .. } => Status::Timeout,
+            _ => Status::Error,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Error => write!(f, "error"),
+            Status::Timeout => write!(f, "timeout"),
+        }
+    }
+}
+
+/// Outcome of solving one day, a part that was skipped or failed has neither an answer nor a runtime. The serialized
+/// field names are the schema of the JSON and CSV output.
+#[derive(Debug, Serialize)]
+pub struct DayResult {
+    pub day: u8,
+    pub title: &'static str,
+    pub part1: Option<PuzzleResult>,
+    pub part2: Option<PuzzleResult>,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse_runtime: Duration,
+    #[serde(rename = "part1_ns", serialize_with = "optional_nanos")]
+    pub part1_runtime: Option<Duration>,
+    #[serde(rename = "part2_ns", serialize_with = "optional_nanos")]
+    pub part2_runtime: Option<Duration>,
+    pub status: Status,
+    pub error: Option<String>,
+}
+
+impl DayResult {
+    /// A day whose input could not be fetched or solved.
+    pub fn failed(day: u8, title: &'static str, err: &AocError) -> Self {
+        DayResult {
+            day,
+            title,
+            part1: None,
+            part2: None,
+            parse_runtime: Duration::ZERO,
+            part1_runtime: None,
+            part2_runtime: None,
+            status: Status::of(err),
+            error: Some(err.to_string()),
+        }
+    }
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+fn optional_nanos<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => nanos(duration, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub fn render(results: &[DayResult], format: Format) -> Result<String, AocError> {
+    match format {
+        Format::Table => Ok(table(results)),
+        Format::Json => serde_json::to_string_pretty(results)
+            .map(|json| json + "\n")
+            .map_err(|err| AocError::Io(format!("failed to serialize the results: {err}"))),
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(vec![]);
+            for result in results {
+                writer.serialize(result).map_err(|err| AocError::Io(format!("failed to serialize the results: {err}")))?;
+            }
+            let bytes = writer.into_inner().map_err(|err| AocError::Io(format!("failed to serialize the results: {err}")))?;
+            String::from_utf8(bytes).map_err(|err| AocError::Io(format!("failed to serialize the results: {err}")))
+        }
+    }
+}
+
+fn table(results: &[DayResult]) -> String {
+    let mut table = format!(
+        "{:<4} {:<32} {:>16} {:>16} {:>12} {:>12} {}\n",
+        "Day", "Title", "Part 1", "Part 2", "Part 1 time", "Part 2 time", "Status"
+    );
+    for result in results {
+        table += &format!(
+            "{:<4} {:<32} {:>16} {:>16} {:>12} {:>12} {}\n",
+            format!("{:02}", result.day), result.title,
+            answer(result, &result.part1), answer(result, &result.part2),
+            runtime(result, result.part1_runtime), runtime(result, result.part2_runtime),
+            result.status
+        );
+    }
+    for result in results {
+        if let Some(error) = &result.error {
+            table += &format!("\nDay {:02}: {error}", result.day);
+        }
+    }
+    if results.iter().any(|result| result.error.is_some()) {
+        table += "\n";
+    }
+    table
+}
+
+fn answer(result: &DayResult, answer: &Option<PuzzleResult>) -> String {
+    answer.as_ref().map_or_else(|| missing(result), PuzzleResult::to_string)
+}
+
+fn runtime(result: &DayResult, runtime: Option<Duration>) -> String {
+    runtime.map_or_else(|| missing(result), |runtime| format!("{runtime:.2?}"))
+}
+
+/// A part without answer was skipped unless the whole day failed.
+fn missing(result: &DayResult) -> String {
+    String::from(if result.status == Status::Ok { "skipped" } else { "-" })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::aoc::common::PuzzleResult;
+    use crate::error::AocError;
+    use crate::report::{render, DayResult, Format, Status};
+
+    fn results() -> Vec<DayResult> {
+        vec![DayResult {
+            day: 15,
+            title: "Lens Library",
+            part1: Some(PuzzleResult::Number(1320)),
+            part2: Some(PuzzleResult::Text(String::from("145"))),
+            parse_runtime: Duration::from_nanos(10),
+            part1_runtime: Some(Duration::from_nanos(1500)),
+            part2_runtime: Some(Duration::from_micros(20)),
+            status: Status::Ok,
+            error: None,
+        }, DayResult {
+            day: 14,
+            title: "Parabolic Reflector Dish",
+            part1: Some(PuzzleResult::Number(136)),
+            part2: None,
+            parse_runtime: Duration::from_nanos(10),
+            part1_runtime: Some(Duration::from_nanos(300)),
+            part2_runtime: None,
+            status: Status::Ok,
+            error: None,
+        }, DayResult::failed(16, "The Floor Will Be Lava", &AocError::HttpStatus(500))]
+    }
+
+    #[test]
+    fn json() {
+        let json = render(&results(), Format::Json).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!([{
+                "day": 15, "title": "Lens Library", "part1": 1320, "part2": "145",
+                "parse_ns": 10, "part1_ns": 1500, "part2_ns": 20000, "status": "ok", "error": null
+            }, {
+                "day": 14, "title": "Parabolic Reflector Dish", "part1": 136, "part2": null,
+                "parse_ns": 10, "part1_ns": 300, "part2_ns": null, "status": "ok", "error": null
+            }, {
+                "day": 16, "title": "The Floor Will Be Lava", "part1": null, "part2": null,
+                "parse_ns": 0, "part1_ns": null, "part2_ns": null, "status": "error", "error": "request failed with HTTP 500"
+            }])
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(&results(), Format::Csv).unwrap(),
+            concat!(
+                "day,title,part1,part2,parse_ns,part1_ns,part2_ns,status,error\n",
+                "15,Lens Library,1320,145,10,1500,20000,ok,\n",
+                "14,Parabolic Reflector Dish,136,,10,300,,ok,\n",
+                "16,The Floor Will Be Lava,,,0,,,error,request failed with HTTP 500\n",
+            )
+        );
+    }
+
+    #[test]
+    fn table() {
+        let table = render(&results(), Format::Table).unwrap();
+        let lines = table.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[1].starts_with("15   Lens Library"));
+        assert!(lines[2].split_whitespace().eq(["14", "Parabolic", "Reflector", "Dish", "136", "skipped", "300.00ns", "skipped", "ok"]));
+        assert!(lines[3].ends_with("-            - error"), "{table}");
+        assert_eq!(lines[5], "Day 16: request failed with HTTP 500");
+    }
+}
@@ -0,0 +1,125 @@
+use itertools::Itertools;
+use crate::aoc::common::PuzzleResult;
+use crate::error::AocError;
+use crate::puzzle::Example;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// Source of the module of a new day, its tests use the examples where they are known.
+pub fn module(year: u16, day: u8, title: &str, examples: &[Example]) -> String {
+    let example = |part: u8| examples.iter().find(|example| example.part == part);
+    let input = |part: u8| example(part).map_or("", |example| example.input.trim_end());
+    let inputs = if input(2).is_empty() || input(1) == input(2) {
+        (format!("    const INPUT: &str = \"{}\";", escape(input(1))), "INPUT", "INPUT")
+    } else {
+        let inputs = format!("    const INPUT_1: &str = \"{}\";\n    const INPUT_2: &str = \"{}\";", escape(input(1)), escape(input(2)));
+        (inputs, "INPUT_1", "INPUT_2")
+    };
+    let answer = |part: u8| {
+        match example(part).and_then(|example| example.answer.as_deref()?.parse().ok()) {
+            Some(PuzzleResult::Number(answer)) => format!("PuzzleResult::Number({answer})"),
+            Some(PuzzleResult::Big(answer)) => format!("PuzzleResult::Big({answer})"),
+            Some(PuzzleResult::Text(answer)) => format!("PuzzleResult::Text(String::from({answer:?}))"),
+            None => String::from("PuzzleResult::Number(0)"),
+        }
+    };
+    TEMPLATE
+        .replace("Day00", &format!("Day{day:02}"))
+        .replace("day00", &format!("day{day:02}"))
+        .replace("__YEAR__", &year.to_string())
+        .replace("__DAY__", &day.to_string())
+        .replace("__TITLE__", &escape(title))
+        .replace("__INPUTS__", &inputs.0)
+        .replace("__INPUT1__", inputs.1)
+        .replace("__INPUT2__", inputs.2)
+        .replace("__PART1__", &answer(1))
+        .replace("__PART2__", &answer(2))
+}
+
+/// Contents of a Rust string literal, line breaks are kept so examples stay readable.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Adds `pub mod dayNN;` and the `SOLVERS` entry of a day to the source of `aoc/yYYYY/mod.rs`, both in day order.
+pub fn register(source: &str, day: u8) -> Result<String, AocError> {
+    let module = format!("pub mod day{day:02};");
+    let solver = format!("    &day{day:02}::Day{day:02},");
+    if source.lines().any(|line| line == module) {
+        return Err(AocError::Usage(format!("day {day} is already registered")));
+    }
+    let mut lines = source.lines().map(String::from).collect::<Vec<String>>();
+    for (new, prefix) in [(module, "pub mod day"), (solver, "    &day")] {
+        let run = lines.iter().positions(|line| line.starts_with(prefix)).collect::<Vec<usize>>();
+        let (Some(&first), Some(&last)) = (run.first(), run.last()) else {
+            return Err(AocError::Io(format!("could not find where to register day {day} (`{prefix}..`)")));
+        };
+        let at = (first..=last).find(|&i| lines[i] > new).unwrap_or(last + 1);
+        lines.insert(at, new);
+    }
+    Ok(lines.join("\n") + if source.ends_with('\n') { "\n" } else { "" })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AocError;
+    use crate::puzzle::Example;
+    use crate::scaffold::{module, register};
+
+    const MOD_RS: &str = "pub mod util;
+pub mod day01;
+pub mod day03;
+
+pub static SOLVERS: &[&dyn Solver] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn registers_in_day_order() {
+        assert_eq!(register(MOD_RS, 2).unwrap(), "pub mod util;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub static SOLVERS: &[&dyn Solver] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+");
+        assert!(register(MOD_RS, 16).unwrap().contains("pub mod day03;\npub mod day16;\n"));
+        assert!(register(MOD_RS, 16).unwrap().contains("    &day03::Day03,\n    &day16::Day16,\n];"));
+        assert_eq!(register(MOD_RS, 3), Err(AocError::Usage(String::from("day 3 is already registered"))));
+        assert!(register("pub mod util;\n", 3).is_err());
+    }
+
+    #[test]
+    fn fills_in_the_template() {
+        let examples = [
+            Example { part: 1, input: String::from("a \"b\"\nc\n"), answer: Some(String::from("42")) },
+            Example { part: 2, input: String::from("a \"b\"\nc\n"), answer: Some(String::from("ab,cd")) },
+        ];
+        let source = module(2023, 16, "The Floor Will Be Lava", &examples);
+        assert!(source.contains("pub struct Day16;\n"));
+        assert!(source.contains("    const DAY: u8 = 16;\n"));
+        assert!(source.contains("    const TITLE: &'static str = \"The Floor Will Be Lava\";\n"));
+        assert!(source.contains("    use crate::aoc::y2023::day16::Day16;\n"));
+        assert!(source.contains("    const INPUT: &str = \"a \\\"b\\\"\nc\";\n"));
+        assert!(source.contains("assert_eq!(Day16.part1(INPUT), Ok(PuzzleResult::Number(42)));"));
+        assert!(source.contains("assert_eq!(Day16.part2(INPUT), Ok(PuzzleResult::Text(String::from(\"ab,cd\"))));"));
+
+        let examples = [
+            Example { part: 1, input: String::from("1\n"), answer: Some(String::from("2")) },
+            Example { part: 2, input: String::from("3\n"), answer: Some(String::from("18446744073709551616")) },
+        ];
+        let source = module(2023, 17, "Clumsy Crucible", &examples);
+        assert!(source.contains("    const INPUT_1: &str = \"1\";\n    const INPUT_2: &str = \"3\";\n"));
+        assert!(source.contains("assert_eq!(Day17.part2(INPUT_2), Ok(PuzzleResult::Big(18446744073709551616)));"));
+
+        let source = module(2023, 17, "Clumsy Crucible", &[]);
+        assert!(source.contains("    const INPUT: &str = \"\";\n"));
+        assert!(source.contains("assert_eq!(Day17.part1(INPUT), Ok(PuzzleResult::Number(0)));"));
+    }
+}
@@ -0,0 +1,302 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use hyper::body::HttpBody;
+use hyper::header::{CONTENT_LENGTH, CONTENT_TYPE};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde::Serialize;
+use tokio::sync::{oneshot, Semaphore};
+use crate::aoc;
+use crate::aoc::common::{Part, PuzzleResult, Solver, SOLVER_STACK_SIZE};
+use crate::error::AocError;
+
+/// Largest accepted input by default, puzzle inputs are a few dozen kilobytes.
+pub const MAX_BODY_BYTES: usize = 1 << 20;
+
+/// Most solves running at once by default.
+pub const MAX_SOLVES: usize = 8;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    pub max_body: usize,
+    pub timeout: Option<Duration>,
+    /// Most solves running at once. A solve that timed out keeps running, since a thread cannot be stopped, and counts
+    /// until it finishes.
+    pub max_solves: usize,
+}
+
+#[derive(Serialize)]
+struct Solution {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: PuzzleResult,
+    parse_ns: u64,
+    runtime_ns: u64,
+}
+
+/// `code` is the exit code the command line gives for the same error.
+#[derive(Serialize)]
+struct Failure {
+    error: String,
+    code: Option<u8>,
+}
+
+/// Answers `POST /solve/{year}/{day}/{part}` with the puzzle input as body until the process is stopped,
+/// `/solve/{day}/{part}` solves a day of `year`.
+pub async fn serve(addr: SocketAddr, year: u16, limits: Limits) -> Result<(), AocError> {
+    let solves = Arc::new(Semaphore::new(limits.max_solves));
+    let service = make_service_fn(move |_| {
+        let solves = Arc::clone(&solves);
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let solves = Arc::clone(&solves);
+                async move { Ok::<_, Infallible>(handle(request, year, limits, solves).await) }
+            }))
+        }
+    });
+    let server = Server::try_bind(&addr)
+        .map_err(|err| AocError::Io(format!("failed to listen on {addr}: {err}")))?
+        .serve(service);
+    println!("Listening on http://{}", server.local_addr());
+    server.await.map_err(|err| AocError::Io(format!("the server failed: {err}")))
+}
+
+async fn handle(request: Request<Body>, year: u16, limits: Limits, solves: Arc<Semaphore>) -> Response<Body> {
+    let path = request.uri().path().trim_matches('/').split('/').collect::<Vec<&str>>();
+    let (year, day, part) = match path[..] {
+        ["solve", day, part] => (Some(year), day.parse::<u8>().ok(), part.parse::<u8>().ok()),
+        ["solve", year, day, part] => (year.parse::<u16>().ok(), day.parse::<u8>().ok(), part.parse::<u8>().ok()),
+        _ => return failure(StatusCode::NOT_FOUND, "expected /solve/{year}/{day}/{part}", None),
+    };
+    if request.method() != Method::POST {
+        return failure(StatusCode::METHOD_NOT_ALLOWED, "POST the puzzle input to solve it", None);
+    }
+    let (Some(year), Some(day)) = (year, day) else {
+        return failure(StatusCode::NOT_FOUND, "no solver for this day", None);
+    };
+    let Some(solver) = aoc::solver(year, day) else {
+        return failure(StatusCode::NOT_FOUND, "no solver for this day", None);
+    };
+    let part = match part {
+        Some(1) => Part::One,
+        Some(2) => Part::Two,
+        _ => return failure(StatusCode::NOT_FOUND, "the part must be 1 or 2", None),
+    };
+    let input = match read_body(request.into_body(), limits.max_body).await {
+        Ok(input) => input,
+        Err(response) => return response,
+    };
+    solve(solver, year, input, part, limits, solves).await
+}
+
+/// Solves a part of `input` with `solver` once a permit of `solves` is free.
+async fn solve(
+    solver: &'static dyn Solver, year: u16, input: String, part: Part, limits: Limits, solves: Arc<Semaphore>,
+) -> Response<Body> {
+    let (day, number) = (solver.day(), if part == Part::One { 1 } else { 2 });
+    let Ok(permit) = solves.try_acquire_owned() else {
+        let busy = format!("too many solves are running, at most {} at once, try again later", limits.max_solves);
+        return failure(StatusCode::SERVICE_UNAVAILABLE, &busy, None);
+    };
+    // solvers block for as long as they take, so they get a thread of their own with a stack as large as on the command
+    // line, which holds the permit until the solver is done even if the request timed out
+    let (sender, receiver) = oneshot::channel();
+    let spawned = thread::Builder::new()
+        .name(format!("solve-day{day:02}-part{number}"))
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let _ = sender.send(solver.run(&input, part, None));
+            drop(permit);
+        });
+    if let Err(err) = spawned {
+        return failure(StatusCode::INTERNAL_SERVER_ERROR, &format!("failed to start a solver thread: {err}"), None);
+    }
+    let run = match limits.timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, receiver).await {
+            Ok(run) => run,
+            Err(_) => return error(&AocError::TimedOut { day, part: number, timeout }),
+        },
+        None => receiver.await,
+    };
+    let run = match run {
+        Ok(Ok(run)) => run,
+        Ok(Err(err)) => return error(&err),
+        Err(_) => return failure(StatusCode::INTERNAL_SERVER_ERROR, "the solver thread exited", None),
+    };
+    let result = match part {
+        Part::One => run.part1,
+        _ => run.part2,
+    };
+    match result {
+        Some(Ok((answer, runtime))) => json(StatusCode::OK, &Solution {
+            year,
+            day,
+            part: number,
+            answer,
+            parse_ns: run.parse.as_nanos() as u64,
+            runtime_ns: runtime.as_nanos() as u64,
+        }),
+        Some(Err(err)) => error(&err),
+        None => failure(StatusCode::INTERNAL_SERVER_ERROR, "the part was not solved", None),
+    }
+}
+
+/// The body as text, or the response rejecting it. A declared length over the limit is refused before reading.
+async fn read_body(mut body: Body, max_body: usize) -> Result<String, Response<Body>> {
+    let too_large = || failure(StatusCode::PAYLOAD_TOO_LARGE, &format!("the input is larger than {max_body} bytes"), None);
+    if body.size_hint().lower() > max_body as u64 {
+        return Err(too_large());
+    }
+    let mut bytes = Vec::new();
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|err| failure(StatusCode::BAD_REQUEST, &format!("failed to read the input: {err}"), None))?;
+        if bytes.len() + chunk.len() > max_body {
+            return Err(too_large());
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    String::from_utf8(bytes).map_err(|_| failure(StatusCode::BAD_REQUEST, "the input is not valid UTF-8", None))
+}
+
+fn error(err: &AocError) -> Response<Body> {
+    let status = match err {
+        AocError::Parse { .. } | AocError::Unsolvable { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        AocError::TimedOut { .. } => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    failure(status, &err.to_string(), Some(err.exit_code()))
+}
+
+fn failure(status: StatusCode, error: &str, code: Option<u8>) -> Response<Body> {
+    json(status, &Failure { error: error.into(), code })
+}
+
+fn json(status: StatusCode, value: &impl Serialize) -> Response<Body> {
+    let body = serde_json::to_string(value).unwrap_or_default();
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .header(CONTENT_LENGTH, body.len())
+        .body(Body::from(body))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use hyper::{Body, Request, StatusCode};
+    use serde_json::{json, Value};
+    use tokio::sync::Semaphore;
+    use crate::aoc;
+    use crate::aoc::common::{Part, Puzzle, PuzzleResult};
+    use crate::error::AocError;
+    use crate::server::{handle, solve, Limits};
+
+    const LIMITS: Limits = Limits { max_body: 64, timeout: None, max_solves: 1 };
+
+    /// Sleeps for as many milliseconds as the input says, without keeping a core busy.
+    struct Sleepy;
+
+    impl Puzzle for Sleepy {
+        type Input = u64;
+
+        const DAY: u8 = 26;
+
+        const TITLE: &'static str = "Sleepy";
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            Ok(input.parse::<u64>().expect("not a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Result<PuzzleResult, AocError> {
+            std::thread::sleep(Duration::from_millis(*input));
+            Ok(PuzzleResult::Number(*input as i64))
+        }
+
+        fn part2(input: &Self::Input) -> Result<PuzzleResult, AocError> {
+            Self::part1(input)
+        }
+    }
+
+    /// Status and JSON body of the response to a request.
+    async fn send(method: &str, path: &str, body: &str, limits: Limits) -> (StatusCode, Value) {
+        send_to(&Arc::new(Semaphore::new(limits.max_solves)), method, path, body, limits).await
+    }
+
+    /// Like `send`, to a server whose running solves are counted by `solves`.
+    async fn send_to(solves: &Arc<Semaphore>, method: &str, path: &str, body: &str, limits: Limits) -> (StatusCode, Value) {
+        let request = Request::builder().method(method).uri(path).body(Body::from(body.to_string())).unwrap();
+        let response = handle(request, 2023, limits, Arc::clone(solves)).await;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn solves() {
+        let (status, body) = send("POST", "/solve/6/2", "Time:      7  15   30\nDistance:  9  40  200", LIMITS).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["answer"], json!(71503));
+        assert_eq!((body["year"].clone(), body["day"].clone(), body["part"].clone()), (json!(2023), json!(6), json!(2)));
+        assert!(body["runtime_ns"].is_u64() && body["parse_ns"].is_u64());
+        let (status, body) = send("POST", "/solve/2023/6/1", "Time:      7  15   30\nDistance:  9  40  200", LIMITS).await;
+        assert_eq!((status, body["answer"].clone()), (StatusCode::OK, json!(288)));
+    }
+
+    #[tokio::test]
+    async fn rejects() {
+        assert_eq!(send("GET", "/solve/6/1", "", LIMITS).await.0, StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(send("POST", "/solve/26/1", "", LIMITS).await.0, StatusCode::NOT_FOUND);
+        assert_eq!(send("POST", "/solve/2022/6/1", "", LIMITS).await.0, StatusCode::NOT_FOUND);
+        assert_eq!(send("POST", "/solve/6/3", "", LIMITS).await.0, StatusCode::NOT_FOUND);
+        assert_eq!(send("POST", "/answers", "", LIMITS).await.0, StatusCode::NOT_FOUND);
+        let (status, body) = send("POST", "/solve/6/1", &"1".repeat(65), LIMITS).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(body, json!({ "error": "the input is larger than 64 bytes", "code": null }));
+    }
+
+    #[tokio::test]
+    async fn solver_errors() {
+        let (status, body) = send("POST", "/solve/6/1", "Time: 7 15\n", LIMITS).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body, json!({ "error": "day 6: invalid input on line 2: expected `Distance:`", "code": 8 }));
+
+        let limits = Limits { timeout: Some(Duration::from_millis(50)), ..LIMITS };
+        let solves = Arc::new(Semaphore::new(limits.max_solves));
+        let response = solve(&Sleepy, 2023, "200".into(), Part::Two, limits, Arc::clone(&solves)).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = serde_json::from_slice::<Value>(&body).unwrap();
+        assert_eq!(body, json!({ "error": "day 26 part 2: timed out after 50ms", "code": 12 }));
+        // wait for the abandoned solve to finish
+        drop(solves.acquire().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn solves_deep_inputs() {
+        let input = aoc::solver(2023, 10).unwrap().generate(1, 168).unwrap();
+        let limits = Limits { max_body: 1 << 20, ..LIMITS };
+        let (status, body) = send("POST", "/solve/10/1", &input, limits).await;
+        assert_eq!((status, body["answer"].clone()), (StatusCode::OK, json!(7056)));
+    }
+
+    #[tokio::test]
+    async fn bounds_running_solves() {
+        let limits = Limits { timeout: Some(Duration::from_millis(50)), ..LIMITS };
+        let solves = Arc::new(Semaphore::new(limits.max_solves));
+        let response = solve(&Sleepy, 2023, "200".into(), Part::One, limits, Arc::clone(&solves)).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        // the abandoned solve still holds its permit
+        let (status, body) = send_to(&solves, "POST", "/solve/6/1", "Time: 7\nDistance: 9\n", limits).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body, json!({ "error": "too many solves are running, at most 1 at once, try again later", "code": null }));
+        // and gives it back once it is done
+        drop(solves.acquire().await.unwrap());
+        assert_eq!(send_to(&solves, "POST", "/solve/6/1", "Time: 7\nDistance: 9\n", limits).await.0, StatusCode::OK);
+    }
+}
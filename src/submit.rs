@@ -0,0 +1,103 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::time::Duration;
+use regex::Regex;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    Unknown,
+}
+
+/// Outcome of posting an answer to `/{year}/day/{day}/answer`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong(Hint),
+    RateLimited(Duration),
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong(Hint::TooHigh) => write!(f, "wrong, the answer is too high"),
+            Verdict::Wrong(Hint::TooLow) => write!(f, "wrong, the answer is too low"),
+            Verdict::Wrong(Hint::Unknown) => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => write!(f, "rate limited, try again in {}s", wait.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+impl Verdict {
+    /// Reads the verdict from the HTML page returned after submitting an answer.
+    pub fn from_html(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Some(Verdict::Wrong(Hint::TooHigh))
+            } else if page.contains("your answer is too low") {
+                Some(Verdict::Wrong(Hint::TooLow))
+            } else {
+                Some(Verdict::Wrong(Hint::Unknown))
+            }
+        } else if page.contains("You gave an answer too recently") {
+            let re = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let wait = re.captures(page).map_or(0, |c| {
+                let minutes = c.get(1).map_or(0, |m| m.as_str().parse::<u64>().unwrap_or(0));
+                let seconds = c[2].parse::<u64>().unwrap_or(0);
+                minutes * 60 + seconds
+            });
+            Some(Verdict::RateLimited(Duration::from_secs(wait)))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::submit::{Hint, Verdict};
+
+    #[test]
+    fn correct() {
+        let page = include_str!("../tests/fixtures/submit/correct.html");
+        assert_eq!(Verdict::from_html(page), Some(Verdict::Correct));
+    }
+
+    #[test]
+    fn wrong() {
+        let page = include_str!("../tests/fixtures/submit/too_high.html");
+        assert_eq!(Verdict::from_html(page), Some(Verdict::Wrong(Hint::TooHigh)));
+        let page = include_str!("../tests/fixtures/submit/too_low.html");
+        assert_eq!(Verdict::from_html(page), Some(Verdict::Wrong(Hint::TooLow)));
+        let page = include_str!("../tests/fixtures/submit/wrong.html");
+        assert_eq!(Verdict::from_html(page), Some(Verdict::Wrong(Hint::Unknown)));
+    }
+
+    #[test]
+    fn rate_limited() {
+        let page = include_str!("../tests/fixtures/submit/rate_limited.html");
+        assert_eq!(Verdict::from_html(page), Some(Verdict::RateLimited(Duration::from_secs(252))));
+        let page = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.";
+        assert_eq!(Verdict::from_html(page), Some(Verdict::RateLimited(Duration::from_secs(34))));
+    }
+
+    #[test]
+    fn already_solved() {
+        let page = include_str!("../tests/fixtures/submit/already_solved.html");
+        assert_eq!(Verdict::from_html(page), Some(Verdict::AlreadySolved));
+    }
+
+    #[test]
+    fn unrecognized() {
+        assert_eq!(Verdict::from_html("<html><body>Something else</body></html>"), None);
+    }
+}
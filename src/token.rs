@@ -0,0 +1,197 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Formatter;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use crate::error::AocError;
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+// Where to look for the session token, in the order they are tried. A doc comment would become the about text
+// of the command.
+#[derive(clap::Args, Debug)]
+pub struct TokenArgs {
+    /// Token to access your puzzle input, only needed when the input is not cached yet
+    #[arg(short, long, global = true)]
+    token: Option<String>,
+    /// File containing the token, used when neither --token nor AOC_SESSION is set
+    #[arg(long, global = true)]
+    token_file: Option<PathBuf>,
+    /// Profile of the config file ($XDG_CONFIG_HOME/aoc2023/config.toml) from which to take the token
+    #[arg(long, global = true, env = "AOC_PROFILE")]
+    profile: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    Flag,
+    Env,
+    File(PathBuf),
+    Profile { name: String, path: PathBuf },
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Flag => write!(f, "--token"),
+            Source::Env => write!(f, "the {SESSION_ENV} environment variable"),
+            Source::File(path) => write!(f, "the token file {}", path.display()),
+            Source::Profile { name, path } => write!(f, "profile `{name}` of {}", path.display()),
+        }
+    }
+}
+
+/// A validated session token together with where it was found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub token: String,
+    pub source: Source,
+}
+
+/// `{config dir}/aoc2023/config.toml`, with a `[profiles.NAME]` table per account:
+///
+/// ```toml
+/// default = "work"
+///
+/// [profiles.work]
+/// token = "53616c7465645f5f..."
+/// ```
+#[derive(Default, Deserialize)]
+struct Config {
+    default: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Deserialize)]
+struct Profile {
+    token: String,
+}
+
+impl TokenArgs {
+    /// Resolves the token from `--token`, `AOC_SESSION`, `--token-file` and the config file, in that order.
+    pub fn resolve(&self) -> Result<Option<Session>, AocError> {
+        self.resolve_with(|name| std::env::var(name).ok())
+    }
+
+    fn resolve_with(&self, env: impl Fn(&str) -> Option<String>) -> Result<Option<Session>, AocError> {
+        let (token, source) = if let Some(token) = &self.token {
+            (token.clone(), Source::Flag)
+        } else if let Some(token) = env(SESSION_ENV).filter(|x| !x.is_empty()) {
+            (token, Source::Env)
+        } else if let Some(path) = &self.token_file {
+            let token = fs::read_to_string(path)
+                .map_err(|err| AocError::Io(format!("failed to read the token file {}: {err}", path.display())))?;
+            (token, Source::File(path.clone()))
+        } else if let Some(path) = config_path(&env) {
+            match profile_token(&path, self.profile.as_deref())? {
+                Some((name, token)) => (token, Source::Profile { name, path }),
+                None => return Ok(None),
+            }
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(Session { token: validate(&token, &source)?, source }))
+    }
+}
+
+fn config_path(env: &impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    let dir = env("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| env("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(dir.join("aoc2023").join("config.toml"))
+}
+
+/// Token of the requested profile, or of the default one if none was requested and the config file defines it.
+fn profile_token(path: &Path, profile: Option<&str>) -> Result<Option<(String, String)>, AocError> {
+    let mut config = match fs::read_to_string(path) {
+        Ok(text) => toml::from_str::<Config>(&text)
+            .map_err(|err| AocError::Io(format!("invalid config file {}: {err}", path.display())))?,
+        Err(err) if err.kind() == ErrorKind::NotFound => Config::default(),
+        Err(err) => return Err(AocError::Io(format!("failed to read config file {}: {err}", path.display()))),
+    };
+    let Some(name) = profile.map(String::from).or(config.default.take()) else { return Ok(None); };
+    match config.profiles.remove(&name) {
+        Some(profile) => Ok(Some((name, profile.token))),
+        None => Err(AocError::Usage(format!("profile `{name}` is not defined in {}", path.display()))),
+    }
+}
+
+/// Session cookies are long hexadecimal strings, a copied `session=` prefix and surrounding whitespace are tolerated.
+fn validate(token: &str, source: &Source) -> Result<String, AocError> {
+    let token = token.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+    if token.len() < 64 || !token.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(AocError::Usage(format!(
+            "the session token from {source} is malformed, expected the hexadecimal value of the `session` cookie"
+        )));
+    }
+    Ok(token.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use crate::error::AocError;
+    use crate::token::{Session, Source, TokenArgs};
+
+    const TOKEN: &str = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    fn args(token: Option<&str>, token_file: Option<&Path>, profile: Option<&str>) -> TokenArgs {
+        TokenArgs { token: token.map(String::from), token_file: token_file.map(Path::to_path_buf), profile: profile.map(String::from) }
+    }
+
+    #[test]
+    fn resolution_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_home = dir.path().to_str().unwrap().to_string();
+        let config = dir.path().join("aoc2023/config.toml");
+        std::fs::create_dir_all(config.parent().unwrap()).unwrap();
+        std::fs::write(&config, format!("default = \"work\"\n[profiles.work]\ntoken = \"{}\"\n[profiles.home]\ntoken = \"{TOKEN}\"\n", "f".repeat(64))).unwrap();
+        let file = dir.path().join("token");
+        std::fs::write(&file, format!("session={TOKEN}\n")).unwrap();
+        let env = |session: Option<&str>| {
+            let (session, config_home) = (session.map(String::from), config_home.clone());
+            move |name: &str| match name {
+                "AOC_SESSION" => session.clone(),
+                "XDG_CONFIG_HOME" => Some(config_home.clone()),
+                _ => None,
+            }
+        };
+        let source = |args: TokenArgs, session: Option<&str>| args.resolve_with(env(session)).unwrap().map(|x| x.source);
+
+        assert_eq!(source(args(Some(TOKEN), Some(&file), None), Some(TOKEN)), Some(Source::Flag));
+        assert_eq!(source(args(None, Some(&file), None), Some(TOKEN)), Some(Source::Env));
+        assert_eq!(source(args(None, Some(&file), None), None), Some(Source::File(file.clone())));
+        assert_eq!(
+            args(None, None, None).resolve_with(env(None)).unwrap(),
+            Some(Session { token: "f".repeat(64), source: Source::Profile { name: "work".into(), path: config.clone() } })
+        );
+        assert_eq!(
+            source(args(None, None, Some("home")), None),
+            Some(Source::Profile { name: "home".into(), path: config.clone() })
+        );
+        assert!(matches!(args(None, None, Some("school")).resolve_with(env(None)), Err(AocError::Usage(_))));
+        assert_eq!(args(None, None, None).resolve_with(|_| None), Ok(None));
+    }
+
+    #[test]
+    fn file_token_is_trimmed() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), format!("session={TOKEN}\n")).unwrap();
+        let session = args(None, Some(file.path()), None).resolve_with(|_| None).unwrap().unwrap();
+        assert_eq!(session.token, TOKEN);
+    }
+
+    #[test]
+    fn malformed_token() {
+        let err = args(Some("abc123"), None, None).resolve_with(|_| None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the session token from --token is malformed, expected the hexadecimal value of the `session` cookie"
+        );
+    }
+}
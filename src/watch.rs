@@ -0,0 +1,129 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use crate::answers::AnswerStore;
+use crate::report::DayResult;
+
+/// How often the watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Detects changes of files by their modification time, a file that appears or disappears counts as changed too.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    modified: Vec<Option<SystemTime>>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let modified = paths.iter().map(|path| modified(path)).collect();
+        Watcher { paths, modified }
+    }
+
+    /// Whether any file changed since the last call, or since the watcher was created.
+    pub fn changed(&mut self) -> bool {
+        let modified = self.paths.iter().map(|path| modified(path)).collect::<Vec<Option<SystemTime>>>();
+        let changed = modified != self.modified;
+        self.modified = modified;
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// One line per solved part and failed day. An answer that differs from the previous run is marked as changed and
+/// compared with the accepted answer if there is one, in color with `ansi`.
+pub fn changes(previous: &[DayResult], current: &[DayResult], answers: &AnswerStore, ansi: bool) -> String {
+    let paint = |color: &str, text: String| if ansi { format!("\x1b[{color}m{text}\x1b[0m") } else { text };
+    let mut lines = String::new();
+    for result in current {
+        let before = previous.iter().find(|before| before.day == result.day);
+        let parts = [(1, &result.part1, result.part1_runtime), (2, &result.part2, result.part2_runtime)];
+        for (part, answer, runtime) in parts {
+            let Some(answer) = answer else { continue };
+            let was = before.and_then(|before| if part == 1 { before.part1.as_ref() } else { before.part2.as_ref() });
+            let mut line = format!("Day {:02} part {part}: {answer}", result.day);
+            if let Some(runtime) = runtime {
+                line += &format!(" in {runtime:.2?}");
+            }
+            match was {
+                Some(was) if was != answer => line += &paint("33", format!(" (changed from {was})")),
+                None if before.is_some() => line += &paint("33", String::from(" (new)")),
+                _ => {}
+            }
+            match answers.get(result.day, part) {
+                Some(accepted) if &accepted == answer => line += &paint("32", String::from(" matches the accepted answer")),
+                Some(accepted) => line += &paint("31", format!(" differs from the accepted answer {accepted}")),
+                None => {}
+            }
+            lines += &line;
+            lines += "\n";
+        }
+        if let Some(error) = &result.error {
+            lines += &paint("31", format!("Day {:02}: {error}", result.day));
+            lines += "\n";
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::answers::AnswerStore;
+    use crate::aoc::common::PuzzleResult;
+    use crate::report::{DayResult, Status};
+    use crate::watch::{changes, Watcher};
+
+    fn result(part1: i64, part2: Option<i64>, error: Option<&str>) -> DayResult {
+        DayResult {
+            day: 7,
+            title: "Camel Cards",
+            part1: Some(PuzzleResult::Number(part1)),
+            part2: part2.map(PuzzleResult::Number),
+            parse_runtime: Duration::ZERO,
+            part1_runtime: Some(Duration::from_micros(15)),
+            part2_runtime: part2.map(|_| Duration::from_micros(20)),
+            status: if error.is_some() { Status::Error } else { Status::Ok },
+            error: error.map(String::from),
+        }
+    }
+
+    #[test]
+    fn compares_with_previous_run_and_accepted_answers() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut answers = AnswerStore::load(dir.path(), 2023).unwrap();
+        answers.record(7, 1, &PuzzleResult::Number(6440));
+        answers.record(7, 2, &PuzzleResult::Number(5905));
+
+        assert_eq!(
+            changes(&[], &[result(6440, None, Some("day 7: no solution: no jokers"))], &answers, false),
+            "Day 07 part 1: 6440 in 15.00µs matches the accepted answer\nDay 07: day 7: no solution: no jokers\n"
+        );
+        let previous = [result(6440, None, None)];
+        assert_eq!(
+            changes(&previous, &[result(6441, Some(5905), None)], &answers, false),
+            "Day 07 part 1: 6441 in 15.00µs (changed from 6440) differs from the accepted answer 6440\n\
+             Day 07 part 2: 5905 in 20.00µs (new) matches the accepted answer\n"
+        );
+        assert_eq!(
+            changes(&previous, &[result(6441, None, None)], &answers, true),
+            "Day 07 part 1: 6441 in 15.00µs\x1b[33m (changed from 6440)\x1b[0m\x1b[31m differs from the accepted answer 6440\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn detects_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day07.txt");
+        let mut watcher = Watcher::new(vec![path.clone()]);
+        assert!(!watcher.changed());
+        std::fs::write(&path, "32T3K 765\n").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(std::time::SystemTime::now() + Duration::from_secs(1)).unwrap();
+        assert!(watcher.changed());
+    }
+}
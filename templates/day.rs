@@ -0,0 +1,36 @@
+use crate::aoc::common::{Puzzle, PuzzleResult};
+use crate::error::AocError;
+
+pub struct Day00;
+
+impl Puzzle for Day00 {
+    type Input = Vec<String>;
+
+    const DAY: u8 = __DAY__;
+
+    const TITLE: &'static str = "__TITLE__";
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> { Ok(input.lines().map(String::from).collect()) }
+
+    fn part1(_lines: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Err(AocError::unsolvable(Self::DAY, "part 1 is not implemented yet"))
+    }
+
+    fn part2(_lines: &Self::Input) -> Result<PuzzleResult, AocError> {
+        Err(AocError::unsolvable(Self::DAY, "part 2 is not implemented yet"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::common::{PuzzleResult, Solver};
+    use crate::aoc::y__YEAR__::day00::Day00;
+
+__INPUTS__
+
+    #[test]
+    fn part1() { assert_eq!(Day00.part1(__INPUT1__), Ok(__PART1__)); }
+
+    #[test]
+    fn part2() { assert_eq!(Day00.part2(__INPUT2__), Ok(__PART2__)); }
+}
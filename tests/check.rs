@@ -0,0 +1,33 @@
+mod common;
+
+use std::path::Path;
+use common::{aoc2023, EXAMPLE_DAY01};
+
+fn check(dir: &Path, answers: &str) -> (i32, String) {
+    std::fs::create_dir_all(dir.join("inputs/2023")).unwrap();
+    std::fs::write(dir.join("inputs/2023/day01.txt"), EXAMPLE_DAY01).unwrap();
+    std::fs::create_dir_all(dir.join("answers")).unwrap();
+    std::fs::write(dir.join("answers/2023.toml"), answers).unwrap();
+    let output = aoc2023(&[
+        "--check", "--day", "1",
+        "--cache-dir", dir.join("inputs").to_str().unwrap(),
+        "--answers-dir", dir.join("answers").to_str().unwrap(),
+    ]);
+    (output.status.code().unwrap(), String::from_utf8_lossy(&output.stdout).into())
+}
+
+#[test]
+fn pass_and_missing() {
+    let dir = tempfile::tempdir().unwrap();
+    let (code, stdout) = check(dir.path(), "[day01]\npart1 = \"142\"\n");
+    assert_eq!(code, 0);
+    assert_eq!(stdout, "Day 01 part 1: PASS (142)\nDay 01 part 2: MISSING (142)\n");
+}
+
+#[test]
+fn fail() {
+    let dir = tempfile::tempdir().unwrap();
+    let (code, stdout) = check(dir.path(), "[day01]\npart1 = \"142\"\npart2 = \"281\"\n");
+    assert_eq!(code, 1);
+    assert_eq!(stdout, "Day 01 part 1: PASS (142)\nDay 01 part 2: FAIL (got 142, expected 281)\n");
+}
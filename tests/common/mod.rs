@@ -0,0 +1,145 @@
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+pub const EXAMPLE_DAY01: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+/// A session token in the format of the website.
+pub const TOKEN: &str = "53616c7465645f5f0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+struct Route {
+    method: &'static str,
+    path: String,
+    status: u16,
+    body: String,
+}
+
+/// Stand-in for adventofcode.com which answers with canned responses and records every request it receives.
+pub struct MockServer {
+    url: String,
+    routes: Arc<Mutex<Vec<Route>>>,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let routes: Arc<Mutex<Vec<Route>>> = Arc::new(Mutex::new(vec![]));
+        let requests: Arc<Mutex<Vec<Request>>> = Arc::new(Mutex::new(vec![]));
+        let (server_routes, server_requests) = (routes.clone(), requests.clone());
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &server_routes, &server_requests);
+            }
+        });
+        MockServer { url, routes, requests }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn route(&self, method: &'static str, path: &str, status: u16, body: &str) -> &Self {
+        self.routes.lock().unwrap().push(Route { method, path: path.into(), status, body: body.into() });
+        self
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn handle(stream: TcpStream, routes: &Mutex<Vec<Route>>, requests: &Mutex<Vec<Request>>) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+    let mut headers = vec![];
+    loop {
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+        let Some((key, value)) = line.trim_end().split_once(':') else { break };
+        headers.push((key.trim().to_string(), value.trim().to_string()));
+    }
+    let request = Request { method, path, headers, body: String::new() };
+    let length = request.header("content-length").and_then(|x| x.parse::<usize>().ok()).unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    let request = Request { body: String::from_utf8_lossy(&body).into(), ..request };
+
+    let (status, body) = routes
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|route| route.method == request.method && route.path == request.path)
+        .map(|route| (route.status, route.body.clone()))
+        .unwrap_or((404, String::from("404 Not Found\n")));
+    requests.lock().unwrap().push(request);
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        reason(status),
+        body.len()
+    ).unwrap();
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        302 => "Found",
+        400 => "Bad Request",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        _ => "Unknown",
+    }
+}
+
+/// Runs the `aoc2023` binary with the given arguments, isolated from the environment and the config file of the user
+/// running the tests. It runs in a scratch directory so the default `inputs`, `answers` and `history` directories stay
+/// out of the repository.
+pub fn aoc2023_command(args: &[&str]) -> Command {
+    let scratch = std::env::temp_dir().join("aoc2023-tests");
+    std::fs::create_dir_all(&scratch).unwrap();
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc2023"));
+    command
+        .args(args)
+        .current_dir(scratch)
+        .env_remove("AOC_BASE_URL")
+        .env_remove("AOC_SESSION")
+        .env_remove("AOC_PROFILE")
+        .env("XDG_CONFIG_HOME", std::env::temp_dir().join("aoc2023-tests-no-config"));
+    command
+}
+
+pub fn aoc2023(args: &[&str]) -> Output {
+    aoc2023_command(args).output().unwrap()
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use common::{aoc2023, MockServer};
+
+const BOTH: &str = include_str!("fixtures/puzzle/both.html");
+
+#[test]
+fn writes_fixtures_from_the_puzzle_page() {
+    let server = MockServer::start();
+    server.route("GET", "/2023/day/1", 200, BOTH);
+    let dir = tempfile::tempdir().unwrap();
+    let cache_dir = dir.path().join("cache");
+    let examples_dir = dir.path().join("examples");
+    let args = [
+        "examples", "--day", "1", "--base-url", server.url(),
+        "--cache-dir", cache_dir.to_str().unwrap(), "--dir", examples_dir.to_str().unwrap(),
+    ];
+
+    let output = aoc2023(&args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(std::fs::read_to_string(examples_dir.join("2023/day01/part1.txt")).unwrap(), "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n");
+    assert_eq!(std::fs::read_to_string(examples_dir.join("2023/day01/part1.expected")).unwrap(), "142\n");
+    assert_eq!(std::fs::read_to_string(examples_dir.join("2023/day01/part2.txt")).unwrap(), "two1nine\neightwothree\n");
+    assert!(!examples_dir.join("2023/day01/part2.expected").exists());
+
+    // fixtures corrected by hand are kept
+    std::fs::write(examples_dir.join("2023/day01/part1.expected"), "143\n").unwrap();
+    let output = aoc2023(&args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("expecting 143, the page gives 142"));
+    assert_eq!(std::fs::read_to_string(examples_dir.join("2023/day01/part1.expected")).unwrap(), "143\n");
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use common::{aoc2023, aoc2023_command, MockServer, EXAMPLE_DAY01, TOKEN};
+
+#[test]
+fn sends_session_cookie_and_caches_input() {
+    let server = MockServer::start();
+    server.route("GET", "/2023/day/1/input", 200, EXAMPLE_DAY01);
+    let cache = tempfile::tempdir().unwrap();
+    let cache_dir = cache.path().to_str().unwrap();
+
+    let output = aoc2023(&["--day", "1", "--token", TOKEN, "--base-url", server.url(), "--cache-dir", cache_dir, "--format", "csv"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let row = stdout.lines().nth(1).unwrap().split(',').collect::<Vec<&str>>();
+    assert_eq!((row[0], row[1], row[2]), ("1", "Trebuchet?!", "142"));
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].header("cookie"), Some(format!("session={TOKEN}").as_str()));
+    assert_eq!(std::fs::read_to_string(cache.path().join("2023/day01.txt")).unwrap(), EXAMPLE_DAY01);
+
+    // a warm cache needs neither a token nor the server
+    let output = aoc2023(&["--day", "1", "--base-url", server.url(), "--cache-dir", cache_dir]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(server.requests().len(), 1);
+}
+
+/// Exit code and stderr of a failed download of day 3.
+fn fetch_error(status: u16, body: &str) -> (i32, String) {
+    let server = MockServer::start();
+    server.route("GET", "/2023/day/3/input", status, body);
+    let cache = tempfile::tempdir().unwrap();
+    let output = aoc2023(&[
+        "--day", "3", "--token", TOKEN, "--base-url", server.url(), "--cache-dir", cache.path().to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+    assert!(!cache.path().join("2023/day03.txt").exists());
+    (output.status.code().unwrap(), String::from_utf8_lossy(&output.stderr).into())
+}
+
+#[test]
+fn locked_day() {
+    let (code, stderr) = fetch_error(404, "Please don't repeatedly request this endpoint before it unlocks!\n");
+    assert!(stderr.contains("day 3 of 2023 is not unlocked yet"), "{stderr}");
+    assert_eq!(code, 6);
+}
+
+#[test]
+fn expired_session() {
+    let (code, stderr) = fetch_error(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n");
+    assert!(stderr.contains("the session token is invalid or has expired"), "{stderr}");
+    assert_eq!(code, 7);
+}
+
+#[test]
+fn bad_request() {
+    let (code, stderr) = fetch_error(400, "400 Bad Request\n");
+    assert!(stderr.contains("HTTP 400"), "{stderr}");
+    assert_eq!(code, 4);
+}
+
+#[test]
+fn server_error() {
+    let (code, stderr) = fetch_error(500, "500 Internal Server Error\n");
+    assert!(stderr.contains("HTTP 500"), "{stderr}");
+    assert_eq!(code, 4);
+}
+
+#[test]
+fn base_url_from_environment() {
+    let server = MockServer::start();
+    server.route("GET", "/2023/day/1/input", 200, EXAMPLE_DAY01);
+    let cache = tempfile::tempdir().unwrap();
+    let output = aoc2023_command(&["--day", "1", "--token", TOKEN, "--cache-dir", cache.path().to_str().unwrap()])
+        .env("AOC_BASE_URL", server.url())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(server.requests().len(), 1);
+}
+
+/// Downloads day 1 with the given extra arguments and environment, returning the cookie sent and stderr.
+fn fetch_with(args: &[&str], envs: &[(&str, &str)]) -> (Option<String>, String) {
+    let server = MockServer::start();
+    server.route("GET", "/2023/day/1/input", 200, EXAMPLE_DAY01);
+    let cache = tempfile::tempdir().unwrap();
+    let mut command = aoc2023_command(&["--day", "1", "--base-url", server.url(), "--cache-dir", cache.path().to_str().unwrap()]);
+    command.args(args).envs(envs.iter().copied());
+    let output = command.output().unwrap();
+    let cookie = server.requests().first().and_then(|x| x.header("cookie").map(String::from));
+    (cookie, String::from_utf8_lossy(&output.stderr).into())
+}
+
+#[test]
+fn token_from_environment() {
+    let (cookie, stderr) = fetch_with(&["--verbose"], &[("AOC_SESSION", TOKEN)]);
+    assert_eq!(cookie, Some(format!("session={TOKEN}")));
+    assert!(stderr.contains("Using the session token from the AOC_SESSION environment variable"), "{stderr}");
+}
+
+#[test]
+fn token_from_profile() {
+    let config = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(config.path().join("aoc2023")).unwrap();
+    std::fs::write(
+        config.path().join("aoc2023/config.toml"),
+        format!("default = \"home\"\n\n[profiles.home]\ntoken = \"{}\"\n\n[profiles.work]\ntoken = \"{TOKEN}\"\n", "0".repeat(128)),
+    ).unwrap();
+    let config_home = config.path().to_str().unwrap();
+    let (cookie, stderr) = fetch_with(&["--profile", "work", "-v"], &[("XDG_CONFIG_HOME", config_home)]);
+    assert_eq!(cookie, Some(format!("session={TOKEN}")));
+    assert!(stderr.contains("Using the session token from profile `work`"), "{stderr}");
+    let (cookie, _) = fetch_with(&[], &[("XDG_CONFIG_HOME", config_home)]);
+    assert_eq!(cookie, Some(format!("session={}", "0".repeat(128))));
+}
+
+#[test]
+fn malformed_token() {
+    let (cookie, stderr) = fetch_with(&["--token", "abc123"], &[]);
+    assert_eq!(cookie, None);
+    assert!(stderr.contains("the session token from --token is malformed"), "{stderr}");
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use std::path::Path;
+use common::{aoc2023, EXAMPLE_DAY01};
+
+fn solve(dir: &Path, extra: &[&str]) -> std::process::Output {
+    let (inputs, history) = (dir.join("inputs"), dir.join("history"));
+    let mut args = vec!["--day", "1", "--cache-dir", inputs.to_str().unwrap(), "--history-dir", history.to_str().unwrap()];
+    args.extend(extra);
+    aoc2023(&args)
+}
+
+#[test]
+fn records_runs_and_flags_regressions() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("inputs/2023")).unwrap();
+    std::fs::write(dir.path().join("inputs/2023/day01.txt"), EXAMPLE_DAY01).unwrap();
+
+    // nothing to compare with yet
+    let output = solve(dir.path(), &["--fail-on-regression=10"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    // a negative threshold flags any run that is not more than a thousand times faster than the baseline
+    let output = solve(dir.path(), &["--fail-on-regression=-99.9"]);
+    assert_eq!(output.status.code(), Some(13));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Day 01 part 1: "), "{stderr}");
+    assert!(stderr.contains("slower than the baseline"), "{stderr}");
+
+    let history = std::fs::read_to_string(dir.path().join("history/2023.jsonl")).unwrap();
+    assert_eq!(history.lines().count(), 2);
+    assert!(history.lines().all(|line| line.contains(r#""day":1,"#)));
+
+    let output = aoc2023(&["history", "--day", "1", "--history-dir", dir.path().join("history").to_str().unwrap()]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 3, "{stdout}");
+    assert!(stdout.lines().nth(2).unwrap().contains('%'), "{stdout}");
+}
+
+#[test]
+fn input_files_are_not_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("day01.txt");
+    std::fs::write(&input, EXAMPLE_DAY01).unwrap();
+    let output = solve(dir.path(), &["--input", input.to_str().unwrap()]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(!dir.path().join("history").exists());
+}
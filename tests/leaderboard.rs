@@ -0,0 +1,51 @@
+mod common;
+
+use common::{aoc2023, MockServer, TOKEN};
+
+const JSON: &str = include_str!("fixtures/leaderboard/2023.json");
+
+#[test]
+fn downloads_at_most_every_15_minutes() {
+    let server = MockServer::start();
+    server.route("GET", "/2023/leaderboard/private/view/101.json", 200, JSON);
+    let cache = tempfile::tempdir().unwrap();
+    let args = ["leaderboard", "--id", "101", "--token", TOKEN, "--base-url", server.url(), "--cache-dir", cache.path().to_str().unwrap()];
+
+    let output = aoc2023(&args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().nth(1), Some("   1 Alice                    15     4  **"), "{stdout}");
+    assert_eq!(server.requests()[0].header("cookie"), Some(format!("session={TOKEN}").as_str()));
+    assert_eq!(std::fs::read_to_string(cache.path().join("2023/leaderboard-101.json")).unwrap(), JSON);
+
+    let output = aoc2023(&args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), stdout);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn from_file() {
+    let file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(file.path(), JSON).unwrap();
+    let output = aoc2023(&["leaderboard", "--file", file.path().to_str().unwrap(), "--day", "2"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\nDay 2 "), "{stdout}");
+    assert!(stdout.contains("\nBob                   12-02 05:05:00 12-02 05:11:40    0:06:40\n"), "{stdout}");
+}
+
+#[test]
+fn without_access() {
+    let server = MockServer::start();
+    server.route("GET", "/2023/leaderboard/private/view/101.json", 200, "<!DOCTYPE html><html></html>");
+    let cache = tempfile::tempdir().unwrap();
+    let cache_dir = cache.path().to_str().unwrap();
+
+    let output = aoc2023(&["leaderboard", "--id", "101", "--token", TOKEN, "--base-url", server.url(), "--cache-dir", cache_dir]);
+    assert_eq!(output.status.code(), Some(7));
+    assert!(!cache.path().join("2023/leaderboard-101.json").exists());
+
+    let output = aoc2023(&["leaderboard", "--id", "101", "--base-url", server.url(), "--cache-dir", cache_dir]);
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,42 @@
+use std::time::Duration;
+use aoc2023::aoc::util::{CharMap, Path, Point};
+use aoc2023::{AocError, Part, PuzzleResult, YEARS};
+
+const EXAMPLE_DAY06: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+#[test]
+fn registry() {
+    assert_eq!(YEARS.iter().map(|year| year.year).collect::<Vec<u16>>(), vec![2023]);
+    assert_eq!(aoc2023::solvers(2023).unwrap().len(), 15);
+    let day06 = aoc2023::solver(2023, 6).unwrap();
+    assert_eq!((day06.day(), day06.title()), (6, "Wait For It"));
+    assert!(aoc2023::solver(2023, 26).is_none());
+    assert!(aoc2023::solver(2022, 6).is_none());
+}
+
+#[test]
+fn solves() {
+    let day06 = aoc2023::solver(2023, 6).unwrap();
+    assert_eq!(day06.part1(EXAMPLE_DAY06), Ok(PuzzleResult::Number(288)));
+    let run = day06.run(EXAMPLE_DAY06, Part::Two, Some(Duration::from_secs(10))).unwrap();
+    assert!(run.part1.is_none());
+    assert_eq!(run.part2.unwrap().unwrap().0.to_string(), "71503");
+    let err = day06.part1("Time: 7\n").unwrap_err();
+    assert_eq!(err, AocError::parse(6, 2, "expected `Distance:`"));
+    assert_eq!(err.exit_code(), 8);
+}
+
+#[test]
+fn grid_types() {
+    let map = CharMap::parse("S-7\n|.|\nL-J").unwrap();
+    let start = map.find_all(|&c| c == 'S')[0];
+    assert_eq!(start, Point { x: 0, y: 0 });
+    let mut path = Path::new(start);
+    for point in [start.right(), start.right().right(), Point { x: 2, y: 1 }, Point { x: 2, y: 2 }, Point { x: 1, y: 2 }, start.down().down(), start.down(), start] {
+        path.push(point);
+    }
+    assert!(path.is_cycle());
+    assert_eq!(path.len(), 9);
+    assert_eq!(start.manhattan(&Point { x: 2, y: 2 }), 4);
+    assert_eq!(map.at(&start.left()), None);
+}
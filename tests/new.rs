@@ -0,0 +1,50 @@
+mod common;
+
+use common::{aoc2023, MockServer, TOKEN};
+
+const PART1: &str = include_str!("fixtures/puzzle/part1.html");
+const MOD_RS: &str = include_str!("../src/aoc/y2023/mod.rs");
+
+#[test]
+fn scaffolds_and_registers_a_day() {
+    let server = MockServer::start();
+    server.route("GET", "/2023/day/16", 200, &PART1.replace("Day 1: Trebuchet?!", "Day 16: The Floor Will Be Lava"));
+    server.route("GET", "/2023/day/16/input", 200, ".|...\\....\n");
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("mod.rs"), MOD_RS).unwrap();
+    let (cache_dir, examples_dir) = (dir.path().join("inputs"), dir.path().join("examples"));
+    let args = [
+        "new", "--day", "16", "--fetch", "--token", TOKEN, "--base-url", server.url(),
+        "--cache-dir", cache_dir.to_str().unwrap(),
+        "--module-dir", dir.path().to_str().unwrap(),
+        "--examples-dir", examples_dir.to_str().unwrap(),
+    ];
+
+    let output = aoc2023(&args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let module = std::fs::read_to_string(dir.path().join("day16.rs")).unwrap();
+    assert!(module.contains("pub struct Day16;"));
+    assert!(module.contains("const TITLE: &'static str = \"The Floor Will Be Lava\";"));
+    assert!(module.contains("const INPUT: &str = \"1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\";"));
+    assert!(module.contains("assert_eq!(Day16.part1(INPUT), Ok(PuzzleResult::Number(142)));"));
+    let registry = std::fs::read_to_string(dir.path().join("mod.rs")).unwrap();
+    assert!(registry.contains("pub mod day15;\npub mod day16;\n"));
+    assert!(registry.contains("    &day15::Day15,\n    &day16::Day16,\n];"));
+    assert!(dir.path().join("examples/2023/day16/part1.expected").exists());
+    assert_eq!(std::fs::read_to_string(dir.path().join("inputs/2023/day16.txt")).unwrap(), ".|...\\....\n");
+
+    // an existing module is never overwritten
+    let output = aoc2023(&args);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("day16.rs already exists"));
+}
+
+#[test]
+fn already_registered() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("mod.rs"), MOD_RS).unwrap();
+    let output = aoc2023(&["new", "--day", "15", "--module-dir", dir.path().to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "Error: day 15 is already registered\n");
+    assert!(!dir.path().join("day15.rs").exists());
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use common::{aoc2023, MockServer, TOKEN};
+
+const PART1: &str = include_str!("fixtures/puzzle/part1.html");
+const BOTH: &str = include_str!("fixtures/puzzle/both.html");
+
+#[test]
+fn caches_the_description() {
+    let server = MockServer::start();
+    server.route("GET", "/2023/day/1", 200, PART1);
+    let cache = tempfile::tempdir().unwrap();
+    let cache_dir = cache.path().to_str().unwrap();
+
+    let output = aoc2023(&["read", "--day", "1", "--base-url", server.url(), "--cache-dir", cache_dir]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("--- Day 1: Trebuchet?! ---\n\n"), "{stdout}");
+    assert!(stdout.contains("    pqr3stu8vwx\n"));
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(server.requests()[0].header("cookie"), None);
+    assert_eq!(std::fs::read_to_string(cache.path().join("2023/day01.html")).unwrap(), PART1);
+
+    // offline from the cache
+    let output = aoc2023(&["read", "--day", "1", "--base-url", "http://127.0.0.1:1", "--cache-dir", cache_dir]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), stdout);
+}
+
+#[test]
+fn refetches_part_two() {
+    let server = MockServer::start();
+    server.route("GET", "/2023/day/1", 200, BOTH);
+    let cache = tempfile::tempdir().unwrap();
+    let cache_dir = cache.path().to_str().unwrap();
+    std::fs::create_dir_all(cache.path().join("2023")).unwrap();
+    std::fs::write(cache.path().join("2023/day01.html"), PART1).unwrap();
+
+    let output = aoc2023(&["read", "--day", "1", "--token", TOKEN, "--base-url", server.url(), "--cache-dir", cache_dir]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("--- Part Two ---"));
+    assert_eq!(server.requests()[0].header("cookie"), Some(format!("session={TOKEN}").as_str()));
+    assert_eq!(std::fs::read_to_string(cache.path().join("2023/day01.html")).unwrap(), BOTH);
+
+    // the cached part 1 is still shown when the server is unreachable
+    std::fs::write(cache.path().join("2023/day01.html"), PART1).unwrap();
+    let output = aoc2023(&["read", "--day", "1", "--token", TOKEN, "--base-url", "http://127.0.0.1:1", "--cache-dir", cache_dir]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("Part Two"));
+}
+
+#[test]
+fn requires_a_day() {
+    let output = aoc2023(&["read"]);
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::Stdio;
+use common::{aoc2023_command, EXAMPLE_DAY01};
+
+/// Kills the server when the test ends, also when it fails.
+struct Server(std::process::Child);
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn post(address: &str, path: &str, body: &str) -> String {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(stream, "POST {path} HTTP/1.1\r\nHost: {address}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+/// Starts `serve` on a free port with the given extra arguments and returns it with its address.
+fn start(args: &[&str]) -> (Server, String) {
+    let mut child = aoc2023_command(&[&["serve", "--port", "0"], args].concat()).stdout(Stdio::piped()).spawn().unwrap();
+    let mut line = String::new();
+    BufReader::new(child.stdout.take().unwrap()).read_line(&mut line).unwrap();
+    let address = line.trim().strip_prefix("Listening on http://").unwrap().to_string();
+    (Server(child), address)
+}
+
+#[test]
+fn solves_over_http() {
+    let (_server, address) = start(&["--max-body", "100"]);
+
+    let response = post(&address, "/solve/2023/1/1", EXAMPLE_DAY01);
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(response.to_lowercase().contains("content-type: application/json\r\n"));
+    assert!(response.contains("\r\n\r\n{\"year\":2023,\"day\":1,\"part\":1,\"answer\":142,\"parse_ns\":"), "{response}");
+
+    let response = post(&address, "/solve/1/1", &"1".repeat(101));
+    assert!(response.starts_with("HTTP/1.1 413 Payload Too Large\r\n"), "{response}");
+}
+
+#[test]
+fn times_out() {
+    let (_server, address) = start(&["--timeout", "0.1"]);
+    // part 2 walks four billion seeds one by one, the server process is killed with the solve still running
+    let input = "seeds: 0 4000000000\n".to_string()
+        + &["seed-to-soil", "soil-to-fertilizer", "fertilizer-to-water", "water-to-light", "light-to-temperature",
+            "temperature-to-humidity", "humidity-to-location"].map(|name| format!("\n{name} map:\n50 98 2\n")).concat();
+    let response = post(&address, "/solve/5/2", &input);
+    assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"), "{response}");
+    assert!(response.contains("\"error\":\"day 5 part 2: timed out after 100ms\",\"code\":12"), "{response}");
+}
@@ -0,0 +1,141 @@
+mod common;
+
+use common::{aoc2023, MockServer, EXAMPLE_DAY01, TOKEN};
+
+#[test]
+fn malformed_input() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("day06.txt");
+    std::fs::write(&input, "Time: 7 15 30\nDistance: 9 40\n").unwrap();
+    let output = aoc2023(&["--day", "6", "--input", input.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(8));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("day 6: invalid input on line 2"), "{stderr}");
+}
+
+#[test]
+fn validates_inputs() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("day07.txt");
+    std::fs::write(&input, "32T3K 765\nT55J5 684\nKK6X7 28\n").unwrap();
+    let output = aoc2023(&["validate", "--day", "7", "--input", input.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(8));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 07: day 7: invalid input on line 3, column 4: expected a card, found `X`\n3 | KK6X7 28\n  |    ^\n"
+    );
+    std::fs::write(&input, "32T3K 765\nT55J5 684\n").unwrap();
+    let output = aoc2023(&["validate", "--day", "7", "--input", input.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Day 07: ok\n");
+}
+
+#[test]
+fn generates_inputs() {
+    let output = aoc2023(&["generate", "--day", "7", "--seed", "3", "--size", "5"]);
+    assert!(output.status.success());
+    let input = String::from_utf8_lossy(&output.stdout).into_owned();
+    assert_eq!(input.lines().count(), 5, "{input}");
+    assert_eq!(aoc2023(&["generate", "--day", "7", "--seed", "3", "--size", "5"]).stdout, output.stdout);
+    assert_ne!(aoc2023(&["generate", "--day", "7", "--seed", "4", "--size", "5"]).stdout, output.stdout);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("day07.txt");
+    std::fs::write(&path, &input).unwrap();
+    let output = aoc2023(&["--day", "7", "--input", path.to_str().unwrap()]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let output = aoc2023(&["generate", "--size", "5"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+/// A loop as long as in the puzzle input, which once overflowed the stack of the solver threads.
+#[test]
+fn solves_real_size_pipe_maze() {
+    let output = aoc2023(&["generate", "--day", "10", "--seed", "1", "--size", "168"]);
+    assert!(output.status.success());
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("day10.txt");
+    std::fs::write(&input, &output.stdout).unwrap();
+    let output = aoc2023(&["--day", "10", "--input", input.to_str().unwrap(), "--format", "csv"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let row = stdout.lines().nth(1).unwrap().split(',').collect::<Vec<&str>>();
+    assert_eq!((row[2], row[3]), ("7056", "7053"));
+}
+
+#[test]
+fn invalid_year() {
+    let output = aoc2023(&["--year", "2022"]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("no days of 2022 are implemented"), "{stderr}");
+    let output = aoc2023(&["--year", "2023", "--day", "25"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn lists_implemented_days() {
+    let output = aoc2023(&["list"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Year Day Title
+2023  01 Trebuchet?!
+"), "{stdout}");
+    assert!(stdout.contains("\n2023  15 Lens Library\n"), "{stdout}");
+}
+
+#[test]
+fn keeps_going_after_failures() {
+    let server = MockServer::start();
+    server
+        .route("GET", "/2023/day/1/input", 200, EXAMPLE_DAY01)
+        .route("GET", "/2023/day/6/input", 200, "Time: 7 15 30\nDistance: 9 40 200\n")
+        .route("GET", "/2023/day/9/input", 500, "500 Internal Server Error\n");
+    let cache = tempfile::tempdir().unwrap();
+    let output = aoc2023(&[
+        "--token", TOKEN, "--base-url", server.url(), "--cache-dir", cache.path().to_str().unwrap(), "--threads", "2",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let row = |day: &str| stdout.lines().find(|line| line.starts_with(day)).unwrap_or_default().to_string();
+    assert!(row("01 ").ends_with("ok"), "{stdout}");
+    assert!(row("06 ").ends_with("ok"), "{stdout}");
+    assert!(row("02 ").ends_with("error"), "{stdout}");
+    assert!(row("09 ").ends_with("error"), "{stdout}");
+    assert!(stdout.contains("Day 02: day 2 of 2023 is not unlocked yet"), "{stdout}");
+    assert!(stdout.contains("Day 09: request failed with HTTP 500"), "{stdout}");
+    // every day was requested even though the second one failed, and the exit code is the one of the first failure
+    assert_eq!(server.requests().len(), 15);
+    assert_eq!(output.status.code(), Some(6));
+}
+
+#[test]
+fn single_part() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("day01.txt");
+    std::fs::write(&input, EXAMPLE_DAY01).unwrap();
+    let output = aoc2023(&["--day", "1", "--part", "1", "--input", input.to_str().unwrap(), "--format", "csv"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let row = stdout.lines().nth(1).unwrap().split(',').collect::<Vec<&str>>();
+    assert_eq!((row[2], row[3], row[6]), ("142", "", ""));
+}
+
+#[test]
+fn timeout() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("day05.txt");
+    // part 2 walks four billion seeds one by one
+    std::fs::write(&input, "seeds: 0 4000000000 1 2\n\
+        \nseed-to-soil map:\n50 98 2\n\
+        \nsoil-to-fertilizer map:\n50 98 2\n\
+        \nfertilizer-to-water map:\n50 98 2\n\
+        \nwater-to-light map:\n50 98 2\n\
+        \nlight-to-temperature map:\n50 98 2\n\
+        \ntemperature-to-humidity map:\n50 98 2\n\
+        \nhumidity-to-location map:\n50 98 2\n").unwrap();
+    let output = aoc2023(&["--day", "5", "--input", input.to_str().unwrap(), "--timeout", "0.2", "--format", "csv"]);
+    assert_eq!(output.status.code(), Some(12));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let row = stdout.lines().nth(1).unwrap().split(',').collect::<Vec<&str>>();
+    assert_eq!((row[2], row[3], row[7], row[8]), ("0", "", "timeout", "day 5 part 2: timed out after 200ms"));
+}
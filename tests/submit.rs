@@ -0,0 +1,59 @@
+mod common;
+
+use common::{aoc2023, MockServer, EXAMPLE_DAY01, TOKEN};
+
+fn submit(page: &str) -> (String, Vec<common::Request>, Option<String>) {
+    let server = MockServer::start();
+    server
+        .route("GET", "/2023/day/1/input", 200, EXAMPLE_DAY01)
+        .route("POST", "/2023/day/1/answer", 200, page);
+    let cache = tempfile::tempdir().unwrap();
+    let output = aoc2023(&[
+        "submit", "--day", "1", "--part", "1", "--token", TOKEN,
+        "--base-url", server.url(), "--cache-dir", cache.path().to_str().unwrap(),
+        "--answers-dir", cache.path().to_str().unwrap(),
+    ]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let answers = std::fs::read_to_string(cache.path().join("2023.toml")).ok();
+    (String::from_utf8_lossy(&output.stdout).into(), server.requests(), answers)
+}
+
+#[test]
+fn posts_answer_with_session_cookie() {
+    let (stdout, requests, answers) = submit(include_str!("fixtures/submit/correct.html"));
+    assert_eq!(stdout.trim(), "Day 1 part 1 answer 142: correct");
+    assert_eq!(answers.as_deref(), Some("[day01]\npart1 = \"142\"\n"));
+    let answer = requests.iter().find(|r| r.method == "POST").unwrap();
+    assert_eq!(answer.header("cookie"), Some(format!("session={TOKEN}").as_str()));
+    assert_eq!(answer.header("content-type"), Some("application/x-www-form-urlencoded"));
+    assert_eq!(answer.body, "level=1&answer=142");
+}
+
+#[test]
+fn wrong_answer() {
+    let (stdout, _, answers) = submit(include_str!("fixtures/submit/too_low.html"));
+    assert_eq!(stdout.trim(), "Day 1 part 1 answer 142: wrong, the answer is too low");
+    assert_eq!(answers, None);
+}
+
+#[test]
+fn rate_limited() {
+    let (stdout, _, _) = submit(include_str!("fixtures/submit/rate_limited.html"));
+    assert_eq!(stdout.trim(), "Day 1 part 1 answer 142: rate limited, try again in 252s");
+}
+
+#[test]
+fn already_solved() {
+    let (stdout, _, _) = submit(include_str!("fixtures/submit/already_solved.html"));
+    assert_eq!(stdout.trim(), "Day 1 part 1 answer 142: already solved");
+}
+
+#[test]
+fn requires_token() {
+    let cache = tempfile::tempdir().unwrap();
+    let input = cache.path().join("day01.txt");
+    std::fs::write(&input, EXAMPLE_DAY01).unwrap();
+    let output = aoc2023(&["submit", "--day", "1", "--part", "1", "--input", input.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("requires a --token"));
+}
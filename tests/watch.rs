@@ -0,0 +1,51 @@
+mod common;
+
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+use std::sync::mpsc;
+use std::time::Duration;
+use common::{aoc2023_command, EXAMPLE_DAY01};
+
+/// Kills the watcher when the test ends, also when it fails.
+struct Watch(std::process::Child);
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+#[test]
+fn solves_again_when_the_input_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("day01.txt");
+    std::fs::write(&input, EXAMPLE_DAY01).unwrap();
+    std::fs::create_dir(dir.path().join("answers")).unwrap();
+    std::fs::write(dir.path().join("answers/2023.toml"), "[day01]\npart1 = \"142\"\n").unwrap();
+    let mut child = aoc2023_command(&[
+        "--day", "1", "--part", "1", "--watch", "--input", input.to_str().unwrap(),
+        "--answers-dir", dir.path().join("answers").to_str().unwrap(),
+    ]).stdout(Stdio::piped()).spawn().unwrap();
+    let stdout = child.stdout.take().unwrap();
+    let _watch = Watch(child);
+    let (sender, lines) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            let _ = sender.send(line.unwrap());
+        }
+    });
+    let next = || lines.recv_timeout(Duration::from_secs(10)).unwrap();
+
+    let line = next();
+    assert!(line.starts_with("Day 01 part 1: 142 in ") && line.ends_with(" matches the accepted answer"), "{line}");
+    assert_eq!(next(), "Waiting for the input to change, press Ctrl-C to stop");
+
+    std::fs::write(&input, "1abc2\n").unwrap();
+    std::fs::File::options().write(true).open(&input).unwrap()
+        .set_modified(std::time::SystemTime::now() + Duration::from_secs(5)).unwrap();
+    assert_eq!(next(), "");
+    let line = next();
+    assert!(line.starts_with("Day 01 part 1: 12 in "), "{line}");
+    assert!(line.ends_with(" (changed from 142) differs from the accepted answer 142"), "{line}");
+}